@@ -0,0 +1,229 @@
+//! Macros of [string_colorization](https://docs.rs/string_colorization) checking the tags of its
+//! markup while compiling, use them through its `macros` feature rather than through this crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, LitStr, Token};
+
+/// Implementation of `cformat!`, use it through the `macros` feature of `string_colorization`,
+/// whose `cformat!` gives the path of the crate before a `;` followed by the format string and its
+/// arguments.
+#[proc_macro]
+pub fn cformat(input: TokenStream) -> TokenStream {
+    let CrateInput { krate, input: FormatInput { markup, arguments } } = syn::parse_macro_input!(input as CrateInput<FormatInput>);
+    match expand_markup(&krate, &markup) {
+        Ok(ExpandedMarkup { format, constants, arguments: colorizations }) => quote! {
+            {
+                #constants
+                #krate::__private::format!(#format #arguments #colorizations)
+            }
+        }.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Implementation of `cwrite!`, use it through the `macros` feature of `string_colorization`,
+/// whose `cwrite!` gives the path of the crate before a `;` followed by the writer, the format
+/// string and its arguments.
+#[proc_macro]
+pub fn cwrite(input: TokenStream) -> TokenStream {
+    let CrateInput { krate, input: WriteInput { writer, format_input: FormatInput { markup, arguments } } } =
+        syn::parse_macro_input!(input as CrateInput<WriteInput>);
+    match expand_markup(&krate, &markup) {
+        Ok(ExpandedMarkup { format, constants, arguments: colorizations }) => quote! {
+            {
+                #constants
+                ::core::write!(#writer, #format #arguments #colorizations)
+            }
+        }.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Path of `string_colorization`, such as `$crate`, followed by a `;` and the input of a macro, so
+/// the expanded code works even when the crate is renamed.
+struct CrateInput<Input> {
+    krate: TokenStream2,
+    input: Input,
+}
+
+impl<Input: Parse> Parse for CrateInput<Input> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut krate = TokenStream2::new();
+        while !input.peek(Token![;]) {
+            krate.extend([input.parse::<TokenTree>()?]);
+        }
+        input.parse::<Token![;]>()?;
+        Ok(CrateInput { krate, input: input.parse()? })
+    }
+}
+
+/// Format string of a macro, written in the tag markup, followed by the arguments of the format
+/// string, which are kept as they are along with the comma before them.
+struct FormatInput {
+    markup: LitStr,
+    arguments: TokenStream2,
+}
+
+impl Parse for FormatInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let markup = input.parse()?;
+        let mut arguments = input.parse::<TokenStream2>()?.into_iter().collect::<Vec<_>>();
+        if matches!(arguments.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == ',') {
+            arguments.pop();
+        }
+        Ok(FormatInput { markup, arguments: arguments.into_iter().collect() })
+    }
+}
+
+/// Writer of [cwrite!] followed by its format string and arguments.
+struct WriteInput {
+    writer: Expr,
+    format_input: FormatInput,
+}
+
+impl Parse for WriteInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let writer = input.parse()?;
+        input.parse::<Token![,]>()?;
+        Ok(WriteInput { writer, format_input: input.parse()? })
+    }
+}
+
+/// Format string resulting from a text written in the tag markup, where every change of
+/// colorization is written as a named argument, along with the constants defining the `Colorizer`
+/// of every colorization, as well as checking the names of every tag, and the named arguments
+/// writing the transitions between them.
+struct ExpandedMarkup {
+    format: LitStr,
+    constants: TokenStream2,
+    arguments: TokenStream2,
+}
+
+/// Turns a text written in the tag markup into a format string, following the same rules as
+/// `parse_markup` does, and failing with the same messages as its `MarkupError`.
+///
+/// Placeholders such as `{:<5}`, along with `{{` and `}}`, are copied as they are, so a `<` within
+/// them isn't taken as a tag, and every time the colorization changes before some text or
+/// placeholder, the shortest escape sequence turning the previous colorization into the new one
+/// is written, just like `StyledString::render` does.
+///
+/// The names within tags are checked by constants calling the same `const fn` as `parse_markup`
+/// does, so they fail to compile when a name isn't a color nor a style.
+fn expand_markup(krate: &TokenStream2, markup: &LitStr) -> syn::Result<ExpandedMarkup> {
+    let markup_value = markup.value();
+    let error = |message: String| syn::Error::new(markup.span(), message);
+    let mut format = String::new();
+    let mut constants = TokenStream2::new();
+    let mut open_tags: Vec<(&str, usize)> = Vec::new();
+    let mut colorizations: Vec<Vec<&str>> = Vec::new();
+    let mut transitions: Vec<(Option<usize>, Option<usize>)> = Vec::new();
+    let mut written_colorization: Option<usize> = None;
+    let mut offset = 0;
+    while let Some(character) = markup_value[offset..].chars().next() {
+        let text = match character {
+            '\\' => {
+                let escaped = markup_value[offset + 1..].chars().next();
+                let text = match escaped {
+                    Some('{') => "{{",
+                    Some('}') => "}}",
+                    Some(escaped) => &markup_value[offset + 1..offset + 1 + escaped.len_utf8()],
+                    None => "\\",
+                };
+                offset += 1 + escaped.map_or(0, char::len_utf8);
+                text
+            }
+            '{' | '}' => {
+                let len = if markup_value[offset + 1..].starts_with(character) {
+                    2
+                } else if character == '{' {
+                    markup_value[offset..].find('}').map_or(markup_value.len() - offset, |end| end + 1)
+                } else {
+                    1
+                };
+                offset += len;
+                &markup_value[offset - len..offset]
+            }
+            '<' => {
+                let tag_len = markup_value[offset..].find('>')
+                    .ok_or_else(|| error(format!("the tag at byte {offset} is never terminated by a '>'")))?;
+                let tag = &markup_value[offset + 1..offset + tag_len];
+                if let Some(closed_tag) = tag.strip_prefix('/') {
+                    let (open_tag, _) = open_tags.pop()
+                        .ok_or_else(|| error(format!("the tag at byte {offset} closes a tag, but no tag is open")))?;
+                    let closed_tag = closed_tag.trim();
+                    if !closed_tag.is_empty() && closed_tag != open_tag.trim() {
+                        return Err(error(format!("the tag at byte {offset} closes '{closed_tag}', but the last open tag is '{}'", open_tag.trim())));
+                    }
+                } else {
+                    for name in tag.split(',').map(str::trim) {
+                        let message = format!("the tag at byte {offset} has the name '{name}', which isn't a color nor a style")
+                            .replace('{', "{{").replace('}', "}}");
+                        constants.extend(quote! {
+                            const _: () = ::core::assert!(#krate::__private::is_tag_name(#name), #message);
+                        });
+                    }
+                    open_tags.push((tag, offset));
+                }
+                offset += tag_len + 1;
+                continue;
+            }
+            character => {
+                offset += character.len_utf8();
+                &markup_value[offset - character.len_utf8()..offset]
+            }
+        };
+        let open_colorization = open_tags.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+        let colorization = (!open_colorization.is_empty()).then(|| {
+            colorizations.iter().position(|colorization| *colorization == open_colorization).unwrap_or_else(|| {
+                colorizations.push(open_colorization);
+                colorizations.len() - 1
+            })
+        });
+        if colorization != written_colorization {
+            push_transition(&mut format, &mut transitions, written_colorization, colorization);
+            written_colorization = colorization;
+        }
+        format.push_str(text);
+    }
+    if let Some((tag, offset)) = open_tags.pop() {
+        return Err(error(format!("the tag '{}' at byte {offset} is never closed", tag.trim())));
+    }
+    if written_colorization.is_some() {
+        push_transition(&mut format, &mut transitions, written_colorization, None);
+    }
+
+    let constant = |colorization: Option<usize>| match colorization {
+        Some(index) => format_ident!("__SC_{}", index),
+        None => format_ident!("__SC_PLAIN"),
+    };
+    for (index, colorization) in colorizations.iter().enumerate() {
+        let constant = constant(Some(index));
+        constants.extend(quote! {
+            const #constant: #krate::Colorizer = #krate::Colorizer::new()
+                #(.join_with(#krate::__private::tag_colorizer(#colorization)))*;
+        });
+    }
+    if transitions.iter().any(|(from, to)| from.is_none() || to.is_none()) {
+        constants.extend(quote! { const __SC_PLAIN: #krate::Colorizer = #krate::Colorizer::new(); });
+    }
+    let mut arguments = TokenStream2::new();
+    for (index, (from, to)) in transitions.into_iter().enumerate() {
+        let argument = format_ident!("__sc_{}", index);
+        let (from, to) = (constant(from), constant(to));
+        arguments.extend(quote! { , #argument = #krate::__private::Transition(&#from, &#to) });
+    }
+    Ok(ExpandedMarkup { format: LitStr::new(&format, markup.span()), constants, arguments })
+}
+
+/// Writes into a format string the named argument of the transition between two colorizations,
+/// where [None] is the lack of any, adding it to the given ones when it isn't there yet.
+fn push_transition(format: &mut String, transitions: &mut Vec<(Option<usize>, Option<usize>)>, from: Option<usize>, to: Option<usize>) {
+    let index = transitions.iter().position(|transition| *transition == (from, to)).unwrap_or_else(|| {
+        transitions.push((from, to));
+        transitions.len() - 1
+    });
+    format.push_str(&format!("{{__sc_{index}}}"));
+}
@@ -0,0 +1,319 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{Color, Colorized, Colorizer, MergeStrategy, RenderOptions, Rule, Styles, resolve_segments, sytle_to_index};
+use crate::color::NAMED_COLORS;
+use unicode_width::UnicodeWidthStr;
+
+/// Parses a text that is already colorized through escape sequences, such as the output of other
+/// programs, returning the text without any of them, along with the ranges of bytes of this text
+/// that are colorized and their [Colorizer], sorted by their start and without overlapping.
+///
+/// Every Select Graphic Rendition (SGR) sequence is understood, including named, 256 palette and
+/// true colors, as well as every [Styles], while any other escape sequence, such as those moving
+/// the cursor or setting hyperlinks, is just removed:
+///
+/// ```rust
+/// use string_colorization::{background, foreground, style};
+///
+/// let (text, spans) = string_colorization::parse_ansi("\x1b[1;31mError\x1b[0m: \x1b[38;5;208;48;2;0;0;0mdisk\x1b[22m full\x1b[m");
+/// assert_eq!(text, "Error: disk full");
+/// assert_eq!(spans, [
+///     (0..5, style::Bold + foreground::Red),
+///     (7..16, foreground::ansi256(208) + background::true_color(0, 0, 0)),
+/// ]);
+/// ```
+///
+/// These spans can then be given back as [Rule](crate::Rule)s to colorize the text again, such as
+/// to recolor parts of it or to write it as HTML:
+///
+/// ```rust
+/// use string_colorization::{foreground, Rule};
+///
+/// let (text, spans) = string_colorization::parse_ansi("\x1b[32mok\x1b[0m: 3 passed");
+/// let rules = spans.into_iter().map(|(range, colorizer)| Rule::bytes(range, colorizer))
+///     .chain([Rule::slice(&text[4..5], foreground::Cyan)]);
+/// let colorized = string_colorization::colorize_lazy(&text, None, rules);
+/// assert_eq!(colorized.to_html(), "<span style=\"color:#00cd00\">ok</span>: <span style=\"color:#00cdcd\">3</span> passed");
+/// ```
+pub fn parse_ansi(input: &str) -> (String, Vec<(Range<usize>, Colorizer)>) {
+    let parsed = parse_ansi_chunks(input);
+    (parsed.text, parsed.spans)
+}
+
+/// Colorizes every substring over a text just like [colorize](crate::colorize) does, but where
+/// the text might already be colorized through escape sequences, such as the output of other
+/// programs, which are kept rather than being split or reset by the new colorizations.
+///
+/// Escape sequences that don't colorize the text, such as those moving the cursor or setting
+/// hyperlinks, are written back at the same position of the visible text:
+///
+/// ```rust
+/// use string_colorization::{foreground, Rule};
+///
+/// let output = "see \x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\ now";
+/// let colorized = string_colorization::colorize_ansi(output, None, [Rule::literal("docs", foreground::Blue)]);
+/// assert_eq!(colorized, "see \x1b[34m\x1b]8;;https://docs.rs\x1b\\docs\x1b[0m\x1b]8;;\x1b\\ now");
+/// ```
+///
+/// The rules apply over the text without its escape sequences, so their ranges count visible
+/// bytes or characters, while slices of the input apply over the same visible text they contain,
+/// and the colors and styles the text already has apply beneath every rule, being joined with
+/// them according to the [MergeStrategy](crate::MergeStrategy) of each one:
+///
+/// ```rust
+/// use string_colorization::{foreground, style, MergeStrategy, Rule};
+///
+/// let output = "\x1b[32mok\x1b[0m: 3 passed, 1 failed";
+/// let colorized = string_colorization::colorize_ansi(output, None, [
+///     Rule::bytes(0..2, style::Bold),
+///     Rule::literal("failed", foreground::Red),
+///     Rule::slice(&output[13..14], foreground::Cyan),
+/// ]);
+/// assert_eq!(colorized, "\x1b[1;32mok\x1b[0m: \x1b[36m3\x1b[0m passed, 1 \x1b[31mfailed\x1b[0m");
+///
+/// let recolored = string_colorization::colorize_ansi(output, None, [
+///     Rule::bytes(0..2, foreground::Blue).merge(MergeStrategy::Replace),
+/// ]);
+/// assert_eq!(recolored, "\x1b[34mok\x1b[0m: 3 passed, 1 failed");
+/// ```
+pub fn colorize_ansi<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> String {
+    let mut output = String::with_capacity(input.len());
+    colorize_ansi_lazy(input, general_colorization, input_modifiers).write_to(&mut output)
+        .expect("Writing into a String never fails");
+    output
+}
+
+/// Resolves which colorization applies to every substring of a text that might already be
+/// colorized just like [colorize_ansi] does, but returns a [Colorized] that is only written when
+/// displayed, where [Colorized::input] is the text without its escape sequences, which are only
+/// kept when written through [Display](core::fmt::Display) or [Colorized::write_to], as HTML and
+/// SVG have no use for them:
+///
+/// ```rust
+/// use string_colorization::foreground;
+///
+/// let output = "\x1b[1mBuilding\x1b[0m crate";
+/// let colorized = string_colorization::colorize_ansi_lazy(output, None, [(&output[17..], foreground::Cyan)]);
+/// assert_eq!(colorized.input(), "Building crate");
+/// assert_eq!(colorized.to_html(), "<span style=\"font-weight:bold\">Building</span> <span style=\"color:#00cdcd\">crate</span>");
+/// ```
+pub fn colorize_ansi_lazy<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> Colorized<'input> {
+    let ParsedAnsi { text, spans, chunks, sequences } = parse_ansi_chunks(input);
+    let text = if text.len() == input.len() { Cow::Borrowed(input) } else { Cow::Owned(text) };
+    // Offsets of the input turn into the offset of the same visible byte, or into the end of the
+    // visible text before them when they are part of an escape sequence.
+    let translate_offset = |offset: usize| {
+        let chunk_index = chunks.partition_point(|(input_start, _)| *input_start <= offset);
+        let Some(&(input_start, text_start)) = chunk_index.checked_sub(1).map(|chunk_index| &chunks[chunk_index]) else {
+            return 0;
+        };
+        let text_end = chunks.get(chunk_index).map_or(text.len(), |(_, next_text_start)| *next_text_start);
+        (text_start + offset - input_start).min(text_end)
+    };
+    let rules = input_modifiers.into_iter().map(|rule| rule.into().translate_slice(input, translate_offset));
+    let (segments, rejected_rules) = resolve_segments(&text, general_colorization, spans, rules, MergeStrategy::Layer);
+    Colorized { input: text, segments, sequences, rejected_rules, render_options: RenderOptions::new() }
+}
+
+/// Text without escape sequences along with its colorized ranges, where every chunk of text
+/// between escape sequences starts, both within the input and within the text, and the escape
+/// sequences that don't colorize it along with the offset of the text they are found at.
+struct ParsedAnsi<'input> {
+    text: String,
+    spans: Vec<(Range<usize>, Colorizer)>,
+    chunks: Vec<(usize, usize)>,
+    sequences: Vec<(usize, &'input str)>,
+}
+
+/// Parses a text that is already colorized just like [parse_ansi] does, also finding out where
+/// every chunk of text between escape sequences starts and keeping the escape sequences that don't
+/// colorize it.
+fn parse_ansi_chunks(input: &str) -> ParsedAnsi<'_> {
+    let mut text = String::with_capacity(input.len());
+    let mut spans: Vec<(Range<usize>, Colorizer)> = Vec::new();
+    let mut chunks = Vec::new();
+    let mut sequences = Vec::new();
+    let mut current = Colorizer::new();
+    let mut rest = input;
+    while !rest.is_empty() {
+        let text_len = rest.find('\x1b').unwrap_or(rest.len());
+        if text_len > 0 {
+            let start = text.len();
+            chunks.push((input.len() - rest.len(), start));
+            text.push_str(&rest[..text_len]);
+            match spans.last_mut() {
+                Some((range, colorizer)) if range.end == start && *colorizer == current => range.end = text.len(),
+                _ if current.is_plain() => {}
+                _ => spans.push((start..text.len(), current.clone())),
+            }
+            rest = &rest[text_len..];
+            continue;
+        }
+        let (sequence, after_sequence) = split_escape_sequence(rest);
+        match sequence.strip_prefix("\x1b[").and_then(|sequence| sequence.strip_suffix('m')) {
+            Some(parameters) => apply_sgr(&mut current, parameters),
+            None => sequences.push((text.len(), sequence)),
+        }
+        rest = after_sequence;
+    }
+    ParsedAnsi { text, spans, chunks, sequences }
+}
+
+/// Removes every escape sequence from a text, such as those colorizing it, returning the text as
+/// is when it doesn't have any:
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use string_colorization::foreground;
+///
+/// let colorized = format!("{}, no red", foreground::Red.apply("Red"));
+/// assert_eq!(string_colorization::strip_ansi(&colorized), "Red, no red");
+/// assert!(matches!(string_colorization::strip_ansi("Plain"), Cow::Borrowed("Plain")));
+/// ```
+pub fn strip_ansi(input: &str) -> Cow<'_, str> {
+    if !input.contains('\x1b') {
+        return Cow::Borrowed(input);
+    }
+    let mut text = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(escape) = rest.find('\x1b') {
+        text.push_str(&rest[..escape]);
+        rest = split_escape_sequence(&rest[escape..]).1;
+    }
+    text.push_str(rest);
+    Cow::Owned(text)
+}
+
+/// Returns the amount of columns a terminal takes to show a text, ignoring its escape sequences,
+/// where East Asian wide characters take two columns, while combining marks and the characters
+/// joined into an emoji by zero width joiners don't take any, so colorized texts can be aligned
+/// even though their length in bytes includes every escape sequence:
+///
+/// ```rust
+/// use string_colorization::foreground;
+///
+/// let colorized = foreground::Red.apply("日本");
+/// assert_eq!(colorized.len(), 15);
+/// assert_eq!(string_colorization::visible_width(&colorized), 4);
+/// assert_eq!(string_colorization::visible_width("e\u{301}"), 1);
+/// assert_eq!(string_colorization::visible_width("👩\u{200d}🔬"), 2);
+/// assert_eq!(string_colorization::visible_width("a\tb\x07\r"), 3);
+/// ```
+///
+/// Control characters, such as `BEL` or a carriage return, don't take any column, except for tabs,
+/// which take a single one, as how far they move the cursor depends on the column they are written
+/// at, which isn't known.
+pub fn visible_width(input: &str) -> usize {
+    text_width(&strip_ansi(input))
+}
+
+/// Returns the amount of columns a text without escape sequences takes, see [visible_width].
+pub(crate) fn text_width(text: &str) -> usize {
+    text.split(|character: char| character.is_control())
+        .map(UnicodeWidthStr::width)
+        .sum::<usize>() + text.matches('\t').count()
+}
+
+/// Splits the escape sequence at the start of the given text from the text after it, where control
+/// sequences (`ESC[`) end on a byte within `@` and `~`, operating system commands (`ESC]`) end on a
+/// `BEL` or `ESC\`, and any other sequence is made of the escape and the character after it.
+fn split_escape_sequence(text: &str) -> (&str, &str) {
+    let after_escape = &text[1..];
+    let len = if let Some(control) = after_escape.strip_prefix('[') {
+        control.find(|character| ('@'..='~').contains(&character))
+            .map_or(text.len(), |end| 3 + end)
+    } else if let Some(command) = after_escape.strip_prefix(']') {
+        match (command.find('\x07'), command.find("\x1b\\")) {
+            (Some(bell), Some(terminator)) if terminator < bell => 2 + terminator + 2,
+            (Some(bell), _) => 2 + bell + 1,
+            (None, Some(terminator)) => 2 + terminator + 2,
+            (None, None) => text.len(),
+        }
+    } else {
+        1 + after_escape.chars().next().map_or(0, char::len_utf8)
+    };
+    text.split_at(len)
+}
+
+/// Applies the parameters of an SGR sequence, separated by `;`, over the current [Colorizer],
+/// where extended colors can separate their values either by `;` or `:`.
+pub(crate) fn apply_sgr(current: &mut Colorizer, parameters: &str) {
+    let mut parameters = parameters.split(';');
+    while let Some(parameter) = parameters.next() {
+        let mut sub_parameters = parameter.split(':');
+        let code = sub_parameters.next().and_then(|code| if code.is_empty() { Some(0) } else { code.parse::<u8>().ok() });
+        let Some(code) = code else { continue };
+        match code {
+            0 => *current = Colorizer::new(),
+            1 => *current = current.clone().style(Styles::Bold),
+            2 => *current = current.clone().style(Styles::Dimmed),
+            3 => *current = current.clone().style(Styles::Italic),
+            4 => *current = current.clone().style(Styles::Underline),
+            5 | 6 => *current = current.clone().style(Styles::Blink),
+            7 => *current = current.clone().style(Styles::Reversed),
+            8 => *current = current.clone().style(Styles::Hidden),
+            9 => *current = current.clone().style(Styles::Strikethrough),
+            22 => remove_styles(current, &[Styles::Bold, Styles::Dimmed]),
+            23 => remove_styles(current, &[Styles::Italic]),
+            24 => remove_styles(current, &[Styles::Underline]),
+            25 => remove_styles(current, &[Styles::Blink]),
+            27 => remove_styles(current, &[Styles::Reversed]),
+            28 => remove_styles(current, &[Styles::Hidden]),
+            29 => remove_styles(current, &[Styles::Strikethrough]),
+            30..=37 => current.foreground = Some(NAMED_COLORS[(code - 30) as usize]),
+            90..=97 => current.foreground = Some(NAMED_COLORS[(code - 90 + 8) as usize]),
+            40..=47 => current.background = Some(NAMED_COLORS[(code - 40) as usize]),
+            100..=107 => current.background = Some(NAMED_COLORS[(code - 100 + 8) as usize]),
+            39 => current.foreground = None,
+            49 => current.background = None,
+            38 | 48 => {
+                let color = if parameter.contains(':') {
+                    parse_extended_color(&mut sub_parameters, true)
+                } else {
+                    parse_extended_color(&mut parameters, false)
+                };
+                if code == 38 {
+                    current.foreground = color.or(current.foreground);
+                } else {
+                    current.background = color.or(current.background);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parses the values of an extended color, this is, `5;index` for the 256 colors palette or
+/// `2;r;g;b` for true colors, where colon separated ones might have an empty color space before
+/// the red, green and blue values, such as `2::r:g:b`.
+fn parse_extended_color<'parameters, Parameters: Iterator<Item=&'parameters str>>(values: &mut Parameters, is_colon_separated: bool) -> Option<Color> {
+    let mut parse_next = || values.next().map(|value| value.parse::<u8>().ok());
+    match parse_next()?? {
+        5 => parse_next()?.map(Color::Ansi256),
+        2 => {
+            let mut rgb = [None; 4];
+            let mut len = 0;
+            while len < if is_colon_separated { 4 } else { 3 } {
+                let Some(value) = parse_next() else { break };
+                rgb[len] = value;
+                len += 1;
+            }
+            let [r, g, b] = match len {
+                4 => [rgb[1], rgb[2], rgb[3]],
+                3 => [rgb[0], rgb[1], rgb[2]],
+                _ => return None,
+            };
+            Some(Color::TrueColor { r: r?, g: g?, b: b? })
+        }
+        _ => None,
+    }
+}
+
+/// Removes the given styles from a [Colorizer], leaving it without styles when none remains.
+fn remove_styles(colorizer: &mut Colorizer, styles: &[Styles]) {
+    let removed = styles.iter().fold(0, |removed, style| removed | (1 << sytle_to_index(style)));
+    colorizer.style_const = colorizer.style_const.map(|style_const| style_const & !removed).filter(|style_const| *style_const != 0);
+}
@@ -0,0 +1,311 @@
+/// Colors that a [Colorizer](crate::Colorizer) can set as foreground or background.
+///
+/// These mirror the colors of the [colored](https://docs.rs/colored) crate, and when the
+/// `colored` feature is enabled, they can be converted from and into `colored::Color`, where colors
+/// of the 256 colors palette turn into true colors, as the [colored](https://docs.rs/colored) crate
+/// doesn't support them.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// Color of the 256 colors palette of xterm, where the first 16 are the named colors, the next
+    /// 216 are a 6x6x6 cube of colors, see [Color::cube], and the last 24 are a grayscale ramp, see
+    /// [Color::grayscale].
+    Ansi256(u8),
+    TrueColor { r: u8, g: u8, b: u8 },
+}
+
+/// Named colors, sorted by their index in the 256 colors palette.
+pub(crate) const NAMED_COLORS: [Color; 16] = [Color::Black, Color::Red, Color::Green, Color::Yellow,
+    Color::Blue, Color::Magenta, Color::Cyan, Color::White, Color::BrightBlack, Color::BrightRed,
+    Color::BrightGreen, Color::BrightYellow, Color::BrightBlue, Color::BrightMagenta, Color::BrightCyan,
+    Color::BrightWhite];
+
+/// Names of the 16 named colors in snake case, such as `bright_red`, in the same order as
+/// [NAMED_COLORS], used when colors are written as text, such as in tags of a markup.
+pub(crate) const COLOR_NAMES: [&str; 16] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    "bright_black", "bright_red", "bright_green", "bright_yellow", "bright_blue", "bright_magenta",
+    "bright_cyan", "bright_white"];
+
+/// Names of the styles in snake case, used when styles are written as text, such as in tags of a
+/// markup.
+pub(crate) const STYLE_NAMES: [(&str, Styles); 9] = [("clear", Styles::Clear), ("bold", Styles::Bold),
+    ("dimmed", Styles::Dimmed), ("underline", Styles::Underline), ("reversed", Styles::Reversed),
+    ("italic", Styles::Italic), ("blink", Styles::Blink), ("hidden", Styles::Hidden),
+    ("strikethrough", Styles::Strikethrough)];
+
+/// Parses the hexadecimal digits of a true color written as `#rgb` or `#rrggbb`, without its `#`,
+/// rejecting anything but hexadecimal digits, such as the signs [u8::from_str_radix] accepts.
+pub(crate) const fn parse_hex_color(hex: &str) -> Option<Color> {
+    let hex = hex.as_bytes();
+    if hex.len() != 3 && hex.len() != 6 {
+        return None;
+    }
+    let mut digits = [0_u8; 6];
+    let mut index = 0;
+    while index < hex.len() {
+        digits[index] = match hex[index] {
+            byte @ b'0'..=b'9' => byte - b'0',
+            byte @ b'a'..=b'f' => byte - b'a' + 10,
+            byte @ b'A'..=b'F' => byte - b'A' + 10,
+            _ => return None,
+        };
+        index += 1;
+    }
+    Some(match hex.len() {
+        3 => Color::TrueColor { r: digits[0] * 17, g: digits[1] * 17, b: digits[2] * 17 },
+        _ => Color::TrueColor { r: digits[0] * 16 + digits[1], g: digits[2] * 16 + digits[3], b: digits[4] * 16 + digits[5] },
+    })
+}
+
+/// Intensity of each of the 6 levels of red, green and blue of the colors cube of xterm.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+    /// Creates a color of the 6x6x6 cube of the 256 colors palette, where each of the red, green and
+    /// blue levels go from 0 to 5, being clamped to 5 when higher:
+    ///
+    /// ```rust
+    /// use string_colorization::Color;
+    ///
+    /// assert_eq!(Color::cube(5, 2, 0), Color::Ansi256(208));
+    /// assert_eq!(Color::cube(5, 2, 0).to_rgb(), (255, 135, 0));
+    /// ```
+    pub const fn cube(red: u8, green: u8, blue: u8) -> Color {
+        const fn level(value: u8) -> u8 {
+            if value > 5 { 5 } else { value }
+        }
+        Color::Ansi256(16 + 36 * level(red) + 6 * level(green) + level(blue))
+    }
+
+    /// Creates a color of the grayscale ramp of the 256 colors palette, where the level goes from 0,
+    /// almost black, to 23, almost white, being clamped to 23 when higher:
+    ///
+    /// ```rust
+    /// use string_colorization::Color;
+    ///
+    /// assert_eq!(Color::grayscale(0), Color::Ansi256(232));
+    /// assert_eq!(Color::grayscale(23).to_rgb(), (238, 238, 238));
+    /// ```
+    pub const fn grayscale(level: u8) -> Color {
+        Color::Ansi256(232 + if level > 23 { 23 } else { level })
+    }
+
+    /// Returns the SGR parameter setting this color as foreground, for named colors, this is
+    /// the only parameter, while true colors need further parameters.
+    pub(crate) const fn foreground_code(&self) -> u8 {
+        match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::BrightBlack => 90,
+            Color::BrightRed => 91,
+            Color::BrightGreen => 92,
+            Color::BrightYellow => 93,
+            Color::BrightBlue => 94,
+            Color::BrightMagenta => 95,
+            Color::BrightCyan => 96,
+            Color::BrightWhite => 97,
+            Color::Ansi256(_) | Color::TrueColor { .. } => 38,
+        }
+    }
+
+    /// Returns the red, green and blue values of this color, where named colors and the ones of
+    /// the 256 colors palette take the ones of the default palette of xterm:
+    ///
+    /// ```rust
+    /// use string_colorization::Color;
+    ///
+    /// assert_eq!(Color::Red.to_rgb(), (205, 0, 0));
+    /// assert_eq!(Color::TrueColor { r: 1, g: 2, b: 3 }.to_rgb(), (1, 2, 3));
+    /// ```
+    pub const fn to_rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Color::Black => (0, 0, 0),
+            Color::Red => (205, 0, 0),
+            Color::Green => (0, 205, 0),
+            Color::Yellow => (205, 205, 0),
+            Color::Blue => (0, 0, 238),
+            Color::Magenta => (205, 0, 205),
+            Color::Cyan => (0, 205, 205),
+            Color::White => (229, 229, 229),
+            Color::BrightBlack => (127, 127, 127),
+            Color::BrightRed => (255, 0, 0),
+            Color::BrightGreen => (0, 255, 0),
+            Color::BrightYellow => (255, 255, 0),
+            Color::BrightBlue => (92, 92, 255),
+            Color::BrightMagenta => (255, 0, 255),
+            Color::BrightCyan => (0, 255, 255),
+            Color::BrightWhite => (255, 255, 255),
+            Color::Ansi256(index @ 0..=15) => NAMED_COLORS[*index as usize].to_rgb(),
+            Color::Ansi256(index @ 16..=231) => {
+                let index = *index - 16;
+                (CUBE_LEVELS[(index / 36) as usize], CUBE_LEVELS[(index / 6 % 6) as usize], CUBE_LEVELS[(index % 6) as usize])
+            }
+            Color::Ansi256(index) => {
+                let gray = 8 + 10 * (*index - 232);
+                (gray, gray, gray)
+            }
+            Color::TrueColor { r, g, b } => (*r, *g, *b),
+        }
+    }
+
+    /// Returns the SGR parameter setting this color as background, which is always the
+    /// foreground one plus 10.
+    pub(crate) const fn background_code(&self) -> u8 {
+        self.foreground_code() + 10
+    }
+}
+
+/// Stylizations that a [Colorizer](crate::Colorizer) can apply over a text.
+///
+/// These mirror the styles of the [colored](https://docs.rs/colored) crate, and when the
+/// `colored` feature is enabled, they can be converted from and into `colored::Styles`.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Styles {
+    Clear,
+    Bold,
+    Dimmed,
+    Underline,
+    Reversed,
+    Italic,
+    Blink,
+    Hidden,
+    Strikethrough,
+}
+
+impl Styles {
+    /// Returns the SGR parameter enabling this style, [Styles::Clear] has none, as it just
+    /// means no style at all.
+    pub(crate) const fn code(&self) -> Option<u8> {
+        match self {
+            Styles::Clear => None,
+            Styles::Bold => Some(1),
+            Styles::Dimmed => Some(2),
+            Styles::Italic => Some(3),
+            Styles::Underline => Some(4),
+            Styles::Blink => Some(5),
+            Styles::Reversed => Some(7),
+            Styles::Hidden => Some(8),
+            Styles::Strikethrough => Some(9),
+        }
+    }
+
+    /// Returns the SGR parameter disabling this style, where [Styles::Bold] and [Styles::Dimmed]
+    /// share the same one.
+    pub(crate) const fn off_code(&self) -> Option<u8> {
+        match self.code() {
+            Some(1) => Some(22),
+            Some(code) => Some(code + 20),
+            None => None,
+        }
+    }
+}
+
+#[cfg(feature = "colored")]
+mod colored_interop {
+    use super::{Color, Styles};
+
+    impl From<colored::Styles> for Styles {
+        fn from(value: colored::Styles) -> Self {
+            match value {
+                colored::Styles::Clear => Styles::Clear,
+                colored::Styles::Bold => Styles::Bold,
+                colored::Styles::Dimmed => Styles::Dimmed,
+                colored::Styles::Underline => Styles::Underline,
+                colored::Styles::Reversed => Styles::Reversed,
+                colored::Styles::Italic => Styles::Italic,
+                colored::Styles::Blink => Styles::Blink,
+                colored::Styles::Hidden => Styles::Hidden,
+                colored::Styles::Strikethrough => Styles::Strikethrough,
+            }
+        }
+    }
+
+    impl From<Styles> for colored::Styles {
+        fn from(value: Styles) -> Self {
+            match value {
+                Styles::Clear => colored::Styles::Clear,
+                Styles::Bold => colored::Styles::Bold,
+                Styles::Dimmed => colored::Styles::Dimmed,
+                Styles::Underline => colored::Styles::Underline,
+                Styles::Reversed => colored::Styles::Reversed,
+                Styles::Italic => colored::Styles::Italic,
+                Styles::Blink => colored::Styles::Blink,
+                Styles::Hidden => colored::Styles::Hidden,
+                Styles::Strikethrough => colored::Styles::Strikethrough,
+            }
+        }
+    }
+
+    impl From<colored::Color> for Color {
+        fn from(value: colored::Color) -> Self {
+            match value {
+                colored::Color::Black => Color::Black,
+                colored::Color::Red => Color::Red,
+                colored::Color::Green => Color::Green,
+                colored::Color::Yellow => Color::Yellow,
+                colored::Color::Blue => Color::Blue,
+                colored::Color::Magenta => Color::Magenta,
+                colored::Color::Cyan => Color::Cyan,
+                colored::Color::White => Color::White,
+                colored::Color::BrightBlack => Color::BrightBlack,
+                colored::Color::BrightRed => Color::BrightRed,
+                colored::Color::BrightGreen => Color::BrightGreen,
+                colored::Color::BrightYellow => Color::BrightYellow,
+                colored::Color::BrightBlue => Color::BrightBlue,
+                colored::Color::BrightMagenta => Color::BrightMagenta,
+                colored::Color::BrightCyan => Color::BrightCyan,
+                colored::Color::BrightWhite => Color::BrightWhite,
+                colored::Color::TrueColor { r, g, b } => Color::TrueColor { r, g, b },
+            }
+        }
+    }
+
+    impl From<Color> for colored::Color {
+        fn from(value: Color) -> Self {
+            match value {
+                Color::Black => colored::Color::Black,
+                Color::Red => colored::Color::Red,
+                Color::Green => colored::Color::Green,
+                Color::Yellow => colored::Color::Yellow,
+                Color::Blue => colored::Color::Blue,
+                Color::Magenta => colored::Color::Magenta,
+                Color::Cyan => colored::Color::Cyan,
+                Color::White => colored::Color::White,
+                Color::BrightBlack => colored::Color::BrightBlack,
+                Color::BrightRed => colored::Color::BrightRed,
+                Color::BrightGreen => colored::Color::BrightGreen,
+                Color::BrightYellow => colored::Color::BrightYellow,
+                Color::BrightBlue => colored::Color::BrightBlue,
+                Color::BrightMagenta => colored::Color::BrightMagenta,
+                Color::BrightCyan => colored::Color::BrightCyan,
+                Color::BrightWhite => colored::Color::BrightWhite,
+                Color::Ansi256(_) => {
+                    let (r, g, b) = value.to_rgb();
+                    colored::Color::TrueColor { r, g, b }
+                }
+                Color::TrueColor { r, g, b } => colored::Color::TrueColor { r, g, b },
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::fmt::{Alignment, Display, Formatter, Write};
+use core::ops::Range;
+
+use crate::{ColorSupport, Colorizer, RenderOptions, RuleError, sgr};
+
+/// A text along with the colorizations to apply over it, that only gets colorized when written,
+/// this is returned by [colorize_lazy](crate::colorize_lazy) so colorized text can be used in
+/// `println!` or [format_args!] without creating an intermediate [String](alloc::string::String).
+///
+/// When formatted with a width, such as `{:>20}`, the padding is computed from the columns the
+/// text takes on a terminal rather than from the bytes of the escape sequences, and it's written
+/// outside of them, so the fill characters don't get colorized:
+///
+/// ```rust
+/// use string_colorization::foreground;
+///
+/// let text = "Red";
+/// let colorized = string_colorization::colorize_lazy(text, None, [(&text[..], foreground::Red)]);
+/// assert_eq!(format!("{colorized}"), "\x1b[31mRed\x1b[0m");
+/// assert_eq!(format!("{colorized:>5}"), "  \x1b[31mRed\x1b[0m");
+/// assert_eq!(format!("{colorized:-^7}"), "--\x1b[31mRed\x1b[0m--");
+///
+/// let wide_text = "日本";
+/// let colorized = string_colorization::colorize_lazy(wide_text, None, [(&wide_text[..], foreground::Red)]);
+/// assert_eq!(format!("{colorized:>6}"), "  \x1b[31m日本\x1b[0m");
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Colorized<'input> {
+    pub(crate) input: Cow<'input, str>,
+    pub(crate) segments: Vec<(Range<usize>, Colorizer)>,
+    /// Escape sequences other than the ones colorizing the text, such as hyperlinks, along with the
+    /// offset of the byte of the input they are written before, sorted by it.
+    pub(crate) sequences: Vec<(usize, &'input str)>,
+    pub(crate) rejected_rules: Vec<RuleError>,
+    pub(crate) render_options: RenderOptions,
+}
+
+impl<'input> Colorized<'input> {
+    /// Returns the text this [Colorized] colorizes, without any escape sequence, which is the input
+    /// it was created from unless this input had escape sequences, see
+    /// [colorize_ansi_lazy](crate::colorize_ansi_lazy).
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the errors of the rules that couldn't be applied over the input, which are ignored
+    /// when writing the colorized text:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, RuleError};
+    ///
+    /// let text = "Red, no red";
+    /// let copy = text.to_string();
+    /// let colorized = string_colorization::colorize_lazy(text, None, [
+    ///     (&text[0..3], foreground::Red),
+    ///     (&copy[8..], foreground::Red),
+    /// ]);
+    /// assert_eq!(colorized.to_string(), "\x1b[31mRed\x1b[0m, no red");
+    /// assert_eq!(colorized.rejected_rules(), [RuleError::ForeignSlice { rule_index: 1 }]);
+    /// ```
+    pub fn rejected_rules(&self) -> &[RuleError] {
+        &self.rejected_rules
+    }
+
+    /// Sets the [ColorSupport] of the output this text will be written into, by default
+    /// [ColorSupport::TrueColor], turning every color into the closest one it can show, see
+    /// [Colorizer::downgrade]:
+    ///
+    /// ```rust
+    /// use string_colorization::{ColorSupport, foreground};
+    ///
+    /// let text = "Orange";
+    /// let colorized = string_colorization::colorize_lazy(text, None, [(&text[..], foreground::true_color(255, 140, 10))]);
+    /// assert_eq!(colorized.clone().color_support(ColorSupport::Ansi256).to_string(), "\x1b[38;5;208mOrange\x1b[0m");
+    /// assert_eq!(colorized.clone().color_support(ColorSupport::Ansi16).to_string(), "\x1b[33mOrange\x1b[0m");
+    /// assert_eq!(colorized.color_support(ColorSupport::None).to_string(), "Orange");
+    /// ```
+    pub fn color_support(mut self, color_support: ColorSupport) -> Colorized<'input> {
+        self.render_options = color_support.into();
+        self
+    }
+
+    /// Sets the [RenderOptions] this text will be written with, by default [RenderOptions::new],
+    /// which can also be given as a [ColorSupport] or a
+    /// [TerminalProfile](crate::TerminalProfile):
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, RenderOptions};
+    ///
+    /// let text = "Red";
+    /// let colorized = string_colorization::colorize_lazy(text, None, [(&text[..], foreground::Red)]);
+    /// assert_eq!(colorized.clone().render_options(RenderOptions::new()).to_string(), "\x1b[31mRed\x1b[0m");
+    /// assert_eq!(colorized.render_options(RenderOptions { enabled: false, ..RenderOptions::new() }).to_string(), "Red");
+    /// ```
+    pub fn render_options<Options: Into<RenderOptions>>(mut self, render_options: Options) -> Colorized<'input> {
+        self.render_options = render_options.into();
+        self
+    }
+
+    /// Writes the colorized text into a [core::fmt::Write], which is the same as formatting it
+    /// with `{}`, but without going through a [Formatter].
+    ///
+    /// Rather than writing every colorized part on its own, the state of the terminal is tracked
+    /// so that between two parts only the colors and styles that change are written, all of them
+    /// in a single escape sequence, and a reset is only written once nothing else is colorized:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, style};
+    ///
+    /// let text = "Bold red, then blue";
+    /// let colorized = string_colorization::colorize_lazy(text, Some(style::Bold + background::White), [
+    ///     (&text[5..8], foreground::Red),
+    ///     (&text[15..], foreground::Blue),
+    /// ]);
+    /// let mut output = String::new();
+    /// colorized.write_to(&mut output).unwrap();
+    /// assert_eq!(output, "\x1b[1;47mBold \x1b[31mred\x1b[39m, then \x1b[34mblue\x1b[0m");
+    /// ```
+    pub fn write_to<Writer: Write + ?Sized>(&self, writer: &mut Writer) -> core::fmt::Result {
+        write_segments(writer, &self.input, &self.segments, &self.sequences, self.render_options.color_support())
+    }
+
+    /// Writes the colorized text into a [std::io::Write], this is only available through the `std`
+    /// feature.
+    #[cfg(feature = "std")]
+    pub fn write_to_io<Writer: std::io::Write + ?Sized>(&self, writer: &mut Writer) -> std::io::Result<()> {
+        crate::io::write_through(writer, |writer| self.write_to(writer))
+    }
+}
+
+impl Display for Colorized<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt_padded(f, crate::visible_width(&self.input), |f| self.write_to(f))
+    }
+}
+
+/// Writes a text where the given segments, sorted by their start and without overlapping, are
+/// colorized with the colors the given [ColorSupport] can show, and where the given escape
+/// sequences, sorted by their offset, are written before the byte at their offset.
+///
+/// Rather than writing every segment on its own, the state of the terminal is tracked so that
+/// between two segments only the colors and styles that change are written.
+pub(crate) fn write_segments<Writer: Write + ?Sized>(writer: &mut Writer, input: &str, segments: &[(Range<usize>, Colorizer)], mut sequences: &[(usize, &str)], color_support: ColorSupport) -> core::fmt::Result {
+    let plain = Colorizer::new();
+    let mut current = Colorizer::new();
+    let mut written = 0;
+    for (range, colorizer) in segments {
+        if written < range.start {
+            sgr::write_transition(writer, &current, &plain)?;
+            current = Colorizer::new();
+            write_with_sequences(writer, input, written..range.start, &mut sequences)?;
+        }
+        let colorizer = match color_support {
+            ColorSupport::TrueColor => colorizer.clone(),
+            color_support => colorizer.downgrade(color_support),
+        };
+        sgr::write_transition(writer, &current, &colorizer)?;
+        current = colorizer;
+        write_with_sequences(writer, input, range.clone(), &mut sequences)?;
+        written = range.end;
+    }
+    sgr::write_transition(writer, &current, &plain)?;
+    write_with_sequences(writer, input, written..input.len(), &mut sequences)?;
+    sequences.iter().try_for_each(|(_, sequence)| writer.write_str(sequence))
+}
+
+/// Writes a range of bytes of a text, writing the escape sequences whose offset falls within it
+/// before the byte at their offset, and leaving the rest of them to be written later.
+fn write_with_sequences<Writer: Write + ?Sized>(writer: &mut Writer, input: &str, range: Range<usize>, sequences: &mut &[(usize, &str)]) -> core::fmt::Result {
+    let mut written = range.start;
+    while let Some(((offset, sequence), rest)) = sequences.split_first().filter(|((offset, _), _)| *offset < range.end) {
+        writer.write_str(&input[written..*offset])?;
+        writer.write_str(sequence)?;
+        written = *offset;
+        *sequences = rest;
+    }
+    writer.write_str(&input[written..range.end])
+}
+
+/// Formats a colorized text taking the given amount of columns, padding it with the fill and
+/// alignment of the [Formatter] outside of its escape sequences, so the fill isn't colorized.
+pub(crate) fn fmt_padded<WriteText: FnOnce(&mut Formatter<'_>) -> core::fmt::Result>(f: &mut Formatter<'_>, visible_width: usize, write: WriteText) -> core::fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(visible_width);
+    if padding == 0 {
+        return write(f);
+    }
+    let (padding_before, padding_after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..padding_before {
+        f.write_char(fill)?;
+    }
+    write(f)?;
+    for _ in 0..padding_after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
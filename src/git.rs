@@ -0,0 +1,194 @@
+use alloc::string::{String, ToString};
+use core::fmt::{Display, Formatter, Write};
+
+use crate::{Color, Colorizer, Styles};
+use crate::color::{NAMED_COLORS, parse_hex_color};
+use crate::html::Hex;
+
+impl Colorizer {
+    /// Parses a color value of a git configuration, such as the ones of `color.diff.*` in a
+    /// `.gitconfig`, following the same rules as git does:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, style, Colorizer, GitColorError};
+    ///
+    /// assert_eq!(Colorizer::from_git_color("bold red ul"), Ok(style::Bold + style::Underline + foreground::Red));
+    /// assert_eq!(Colorizer::from_git_color("normal reverse brightblue"), Ok(style::Reversed + background::BrightBlue));
+    /// assert_eq!(Colorizer::from_git_color("208 #1e1e2e"), Ok(foreground::ansi256(208) + background::true_color(30, 30, 46)));
+    /// assert_eq!(Colorizer::from_git_color("red blue green"), Err(GitColorError::TooManyColors { offset: 9 }));
+    /// assert_eq!(Colorizer::from_git_color("red nobold"), Err(GitColorError::NegatedAttribute { offset: 4, word: "nobold".to_string() }));
+    /// assert_eq!(Colorizer::from_git_color("BOLD"), Err(GitColorError::UnknownWord { offset: 0, word: "BOLD".to_string() }));
+    /// ```
+    ///
+    /// - The first color is the foreground and the second one the background, where `normal`,
+    ///   `default` and `-1` leave the color unset, so `normal red` only sets a red background.
+    /// - Colors are `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan` and `white`, along
+    ///   with their bright versions such as `brightred`, an index of the 256 colors palette, where
+    ///   the first 16 are the named colors, or a true color written as `#rrggbb` or `#rgb`.
+    /// - Attributes are `bold`, `dim`, `italic`, `ul`, `blink`, `reverse` and `strike`, matched
+    ///   with the same case just like git does, while `reset` is [Styles::Clear].
+    /// - Attributes preceded by `no` or `no-`, such as `nobold`, turn a style off, which a
+    ///   [Colorizer] can't hold, so they fail rather than being lost.
+    pub fn from_git_color(value: &str) -> Result<Colorizer, GitColorError> {
+        let mut colorizer = Colorizer::new();
+        let mut has_reset = false;
+        let mut colors = 0;
+        for (offset, word) in value.split_whitespace().map(|word| (word.as_ptr() as usize - value.as_ptr() as usize, word)) {
+            if word.eq_ignore_ascii_case("reset") {
+                has_reset = true;
+            } else if let Some(color) = parse_git_color(word) {
+                colorizer = match (colors, color) {
+                    (0, Some(color)) => colorizer.foreground(color),
+                    (1, Some(color)) => colorizer.background(color),
+                    (0 | 1, None) => colorizer,
+                    _ => return Err(GitColorError::TooManyColors { offset }),
+                };
+                colors += 1;
+            } else if let Some((_, style)) = GIT_ATTRIBUTES.iter().find(|(name, _)| *name == word) {
+                colorizer = colorizer.style(*style);
+            } else {
+                let negated = word.strip_prefix("no").map(|attribute| attribute.strip_prefix('-').unwrap_or(attribute));
+                return Err(match negated.filter(|attribute| GIT_ATTRIBUTES.iter().any(|(name, _)| name == attribute)) {
+                    Some(_) => GitColorError::NegatedAttribute { offset, word: word.to_string() },
+                    None => GitColorError::UnknownWord { offset, word: word.to_string() },
+                });
+            }
+        }
+        if has_reset {
+            colorizer = Colorizer::new().style(Styles::Clear).join_with(colorizer);
+        }
+        Ok(colorizer)
+    }
+
+    /// Writes this [Colorizer] as a color value of a git configuration that parses back into it,
+    /// see [Colorizer::from_git_color], failing when it has [Styles::Hidden], as git doesn't
+    /// support it:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, style, Colorizer, GitColorError, Styles};
+    ///
+    /// let colorizer = style::Bold + style::Underline + background::BrightBlack;
+    /// assert_eq!(colorizer.to_git_color(), Ok("bold ul normal brightblack".to_string()));
+    /// assert_eq!(Colorizer::from_git_color(&colorizer.to_git_color().unwrap()), Ok(colorizer));
+    /// assert_eq!((style::Hidden + foreground::Red).to_git_color(), Err(GitColorError::UnsupportedStyle(Styles::Hidden)));
+    /// ```
+    ///
+    /// Colors of the 256 colors palette are written as their index, so the first 16 ones parse back
+    /// as named colors instead.
+    pub fn to_git_color(&self) -> Result<String, GitColorError> {
+        if self.has_style(&Styles::Hidden) {
+            return Err(GitColorError::UnsupportedStyle(Styles::Hidden));
+        }
+        let mut output = String::new();
+        let mut separator = "";
+        for style in self.get_styles() {
+            let name = match GIT_ATTRIBUTES.iter().find(|(_, attribute)| *attribute == style) {
+                Some((name, _)) => name,
+                None => "reset",
+            };
+            write!(output, "{separator}{name}").expect("Writing into a String never fails");
+            separator = " ";
+        }
+        if self.foreground.is_some() || self.background.is_some() {
+            output.push_str(separator);
+            write_git_color(&mut output, self.foreground.as_ref()).expect("Writing into a String never fails");
+        }
+        if self.background.is_some() {
+            output.push(' ');
+            write_git_color(&mut output, self.background.as_ref()).expect("Writing into a String never fails");
+        }
+        Ok(output)
+    }
+}
+
+/// Reason why a color value of a git configuration couldn't be parsed or written, see
+/// [Colorizer::from_git_color] and [Colorizer::to_git_color].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum GitColorError {
+    /// A word isn't a color nor an attribute.
+    UnknownWord { offset: usize, word: String },
+    /// A value has a third color, while only a foreground and a background are allowed.
+    TooManyColors { offset: usize },
+    /// An attribute turns a style off, such as `nobold`, which a [Colorizer] can't hold.
+    NegatedAttribute { offset: usize, word: String },
+    /// A [Colorizer] has a style that git doesn't support.
+    UnsupportedStyle(Styles),
+}
+
+impl GitColorError {
+    /// Position in bytes of the word causing this error, when it was caused by parsing a value.
+    pub const fn offset(&self) -> Option<usize> {
+        match self {
+            GitColorError::UnknownWord { offset, .. } | GitColorError::TooManyColors { offset }
+            | GitColorError::NegatedAttribute { offset, .. } => Some(*offset),
+            GitColorError::UnsupportedStyle(_) => None,
+        }
+    }
+}
+
+impl Display for GitColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GitColorError::UnknownWord { offset, word } => {
+                write!(f, "the word '{word}' at byte {offset} isn't a color nor an attribute")
+            }
+            GitColorError::TooManyColors { offset } => {
+                write!(f, "the color at byte {offset} is a third color, but only two are allowed")
+            }
+            GitColorError::NegatedAttribute { offset, word } => {
+                write!(f, "the attribute '{word}' at byte {offset} turns a style off, but only styles that are on are supported")
+            }
+            GitColorError::UnsupportedStyle(style) => {
+                write!(f, "the style {style:?} isn't supported by git")
+            }
+        }
+    }
+}
+
+impl core::error::Error for GitColorError {}
+
+/// Names of the 8 basic colors in git, where their bright versions are preceded by `bright`.
+const GIT_COLOR_NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white"];
+
+/// Names of the attributes in git along with their style.
+const GIT_ATTRIBUTES: [(&str, Styles); 7] = [("bold", Styles::Bold), ("dim", Styles::Dimmed),
+    ("italic", Styles::Italic), ("ul", Styles::Underline), ("blink", Styles::Blink),
+    ("reverse", Styles::Reversed), ("strike", Styles::Strikethrough)];
+
+/// Parses a color of git, where `Some(None)` is a color that leaves it unset, such as `normal`.
+fn parse_git_color(word: &str) -> Option<Option<Color>> {
+    if ["normal", "default", "-1"].iter().any(|unset| unset.eq_ignore_ascii_case(word)) {
+        return Some(None);
+    }
+    if let Some(hex) = word.strip_prefix('#') {
+        return parse_hex_color(hex).map(Some);
+    }
+    if word.bytes().all(|byte| byte.is_ascii_digit()) {
+        return match word.parse::<u8>().ok()? {
+            index @ 0..=15 => Some(Some(NAMED_COLORS[index as usize])),
+            index => Some(Some(Color::Ansi256(index))),
+        };
+    }
+    let (name, offset) = match word.get(..6).filter(|prefix| prefix.eq_ignore_ascii_case("bright")) {
+        Some(_) => (&word[6..], 8),
+        None => (word, 0),
+    };
+    GIT_COLOR_NAMES.iter().position(|color_name| color_name.eq_ignore_ascii_case(name))
+        .map(|index| Some(NAMED_COLORS[index + offset]))
+}
+
+/// Writes a color of git, where an unset color is written as `normal`.
+fn write_git_color<Writer: Write + ?Sized>(writer: &mut Writer, color: Option<&Color>) -> core::fmt::Result {
+    match color {
+        None => writer.write_str("normal"),
+        Some(Color::Ansi256(index)) => write!(writer, "{index}"),
+        Some(Color::TrueColor { r, g, b }) => write!(writer, "{}", Hex((*r, *g, *b))),
+        Some(named) => {
+            let index = NAMED_COLORS.iter().position(|named_color| named_color == named).expect("Every other color is named");
+            match index {
+                0..=7 => writer.write_str(GIT_COLOR_NAMES[index]),
+                _ => write!(writer, "bright{}", GIT_COLOR_NAMES[index - 8]),
+            }
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use alloc::string::String;
+use core::fmt::{Display, Write};
+
+use crate::{Color, Colorized, Colorizer, Styles};
+use crate::color::NAMED_COLORS;
+
+impl Colorizer {
+    /// Applies this [Colorizer] as HTML rather than as escape sequences, returning the input with
+    /// its special characters escaped and wrapped in a `<span>` whose inline CSS shows the same
+    /// colors and styles, or just escaped when this [Colorizer] doesn't set anything:
+    ///
+    /// ```rust
+    /// use string_colorization::{Colorizer, foreground, style};
+    ///
+    /// let html = (style::Bold + foreground::Red).apply_html("<b> & \"c\"");
+    /// assert_eq!(html, "<span style=\"color:#cd0000;font-weight:bold\">&lt;b&gt; &amp; &quot;c&quot;</span>");
+    /// assert_eq!(Colorizer::new().apply_html("a < b"), "a &lt; b");
+    /// ```
+    ///
+    /// Colors are written through their red, green and blue values, see [Color::to_rgb], and when
+    /// [Styles::Reversed] is set they are swapped, taking the colors of the page in place of the
+    /// ones this [Colorizer] doesn't set:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, style};
+    ///
+    /// assert_eq!((style::Reversed + foreground::Blue).apply_html("Selected"),
+    ///     "<span style=\"color:Canvas;background-color:#0000ee\">Selected</span>");
+    /// ```
+    pub fn apply_html(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len());
+        self.write_html(&mut output, input).expect("Writing into a String never fails");
+        output
+    }
+
+    /// Writes the input string into a [core::fmt::Write] as HTML with this [Colorizer] applied,
+    /// which is the same as writing the result of [Colorizer::apply_html], but without creating any
+    /// [String].
+    pub fn write_html<Writer: Write + ?Sized>(&self, writer: &mut Writer, input: &str) -> core::fmt::Result {
+        if self.is_plain() {
+            return write_escaped(writer, input);
+        }
+        writer.write_str("<span style=\"")?;
+        write_css(writer, self)?;
+        writer.write_str("\">")?;
+        write_escaped(writer, input)?;
+        writer.write_str("</span>")
+    }
+
+    /// Applies this [Colorizer] as HTML just like [Colorizer::apply_html] does, but showing its
+    /// colors and styles through CSS classes defined by the stylesheet of a [Palette] instead of
+    /// through inline CSS, such as `sc-fg-red` for a red foreground or `sc-bold` for bold text:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, style};
+    ///
+    /// assert_eq!((style::Bold + foreground::Red + background::BrightBlack).apply_html_classes("Error"),
+    ///     "<span class=\"sc-fg-red sc-bg-bright-black sc-bold\">Error</span>");
+    /// ```
+    ///
+    /// Colors other than the 16 named ones, or their equivalent [Color::Ansi256] colors, can't be
+    /// restyled by a [Palette], so they are set through the `--sc-fg` and `--sc-bg` custom
+    /// properties of an inline CSS instead, along with the `sc-fg` and `sc-bg` classes using them:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, style};
+    ///
+    /// assert_eq!((style::Underline + foreground::true_color(255, 140, 10)).apply_html_classes("Orange"),
+    ///     "<span class=\"sc-fg sc-underline\" style=\"--sc-fg:#ff8c0a\">Orange</span>");
+    /// ```
+    pub fn apply_html_classes(&self, input: &str) -> String {
+        let mut output = String::with_capacity(input.len());
+        self.write_html_classes(&mut output, input).expect("Writing into a String never fails");
+        output
+    }
+
+    /// Writes the input string into a [core::fmt::Write] as HTML with this [Colorizer] applied
+    /// through CSS classes, which is the same as writing the result of
+    /// [Colorizer::apply_html_classes], but without creating any [String].
+    pub fn write_html_classes<Writer: Write + ?Sized>(&self, writer: &mut Writer, input: &str) -> core::fmt::Result {
+        if self.is_plain() {
+            return write_escaped(writer, input);
+        }
+        writer.write_str("<span class=\"")?;
+        let mut classes = Separated { writer: &mut *writer, separator: ' ', is_first: true };
+        let colors = [("fg", self.foreground), ("bg", self.background)];
+        for (layer, color) in colors.iter().filter_map(|(layer, color)| Some((layer, color.as_ref()?))) {
+            match named_index(color) {
+                Some(index) => classes.push(format_args!("sc-{layer}-{}", NAMED_CLASSES[index]))?,
+                None => classes.push(format_args!("sc-{layer}"))?,
+            }
+        }
+        for style in self.get_styles() {
+            if let Some(class) = style_class(&style) {
+                classes.push(format_args!("sc-{class}"))?;
+            }
+        }
+        writer.write_char('"')?;
+        let mut extended_colors = colors.iter()
+            .filter_map(|(layer, color)| Some((layer, color.filter(|color| named_index(color).is_none())?)))
+            .peekable();
+        if extended_colors.peek().is_some() {
+            writer.write_str(" style=\"")?;
+            let mut declarations = Separated { writer: &mut *writer, separator: ';', is_first: true };
+            for (layer, color) in extended_colors {
+                declarations.push(format_args!("--sc-{layer}:{}", CssColor::Color(color)))?;
+            }
+            writer.write_char('"')?;
+        }
+        writer.write_char('>')?;
+        write_escaped(writer, input)?;
+        writer.write_str("</span>")
+    }
+}
+
+impl Colorized<'_> {
+    /// Returns the colorized text as HTML, where every colorized part is a `<span>` whose inline
+    /// CSS shows its colors and styles, and every special character is escaped, so it can be shown
+    /// on a web page just as a terminal shows it:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground};
+    ///
+    /// let text = "Red & plain";
+    /// let colorized = string_colorization::colorize_lazy(text, None, [
+    ///     (&text[0..3], foreground::Red + background::White),
+    /// ]);
+    /// assert_eq!(colorized.to_html(), "<span style=\"color:#cd0000;background-color:#e5e5e5\">Red</span> &amp; plain");
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut output = String::with_capacity(self.input.len());
+        self.write_html(&mut output).expect("Writing into a String never fails");
+        output
+    }
+
+    /// Writes the colorized text as HTML into a [core::fmt::Write], which is the same as writing
+    /// the result of [Colorized::to_html], but without creating any [String].
+    pub fn write_html<Writer: Write + ?Sized>(&self, writer: &mut Writer) -> core::fmt::Result {
+        self.write_html_segments(writer, Colorizer::write_html)
+    }
+
+    /// Returns the colorized text as HTML just like [Colorized::to_html] does, but showing the
+    /// colors and styles of every part through CSS classes defined by the stylesheet of a [Palette],
+    /// see [Colorizer::apply_html_classes], so the same markup can be restyled by other palettes:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, style};
+    ///
+    /// let text = "Error: disk full";
+    /// let colorized = string_colorization::colorize_lazy(text, None, [
+    ///     (&text[0..5], style::Bold + foreground::Red),
+    /// ]);
+    /// assert_eq!(colorized.to_html_classes(), "<span class=\"sc-fg-red sc-bold\">Error</span>: disk full");
+    /// ```
+    pub fn to_html_classes(&self) -> String {
+        let mut output = String::with_capacity(self.input.len());
+        self.write_html_classes(&mut output).expect("Writing into a String never fails");
+        output
+    }
+
+    /// Writes the colorized text as HTML using CSS classes into a [core::fmt::Write], which is the
+    /// same as writing the result of [Colorized::to_html_classes], but without creating any
+    /// [String].
+    pub fn write_html_classes<Writer: Write + ?Sized>(&self, writer: &mut Writer) -> core::fmt::Result {
+        self.write_html_segments(writer, Colorizer::write_html_classes)
+    }
+
+    /// Writes every part of the text, escaping the ones that aren't colorized and writing the
+    /// colorized ones through the given function.
+    fn write_html_segments<Writer: Write + ?Sized>(&self, writer: &mut Writer,
+                                                   write_segment: fn(&Colorizer, &mut Writer, &str) -> core::fmt::Result) -> core::fmt::Result {
+        let mut written = 0;
+        for (range, colorizer) in &self.segments {
+            write_escaped(writer, &self.input[written..range.start])?;
+            write_segment(colorizer, writer, &self.input[range.clone()])?;
+            written = range.end;
+        }
+        write_escaped(writer, &self.input[written..])
+    }
+}
+
+/// Colors shown for each of the 16 named colors by the stylesheet of the CSS classes written by
+/// [Colorizer::apply_html_classes] and [Colorized::to_html_classes], so that pages with light and
+/// dark themes can show the same markup with different colors, which by default are the ones of
+/// xterm, see [Color::to_rgb]:
+///
+/// ```rust
+/// use string_colorization::{Color, Palette};
+///
+/// let light_theme = Palette::new().color(Color::White, (40, 40, 40)).color(Color::Yellow, (160, 120, 0));
+/// let stylesheet = light_theme.stylesheet();
+/// assert!(stylesheet.contains(".sc-fg-white{--sc-fg:#282828;color:#282828}"));
+/// assert!(stylesheet.contains(".sc-bg-red{--sc-bg:#cd0000;background-color:#cd0000}"));
+/// assert!(stylesheet.contains(".sc-bold{font-weight:bold}"));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct Palette {
+    colors: [(u8, u8, u8); 16],
+}
+
+impl Palette {
+    /// Creates a palette with the colors of xterm.
+    pub const fn new() -> Palette {
+        let mut colors = [(0, 0, 0); 16];
+        let mut index = 0;
+        while index < NAMED_COLORS.len() {
+            colors[index] = NAMED_COLORS[index].to_rgb();
+            index += 1;
+        }
+        Palette { colors }
+    }
+
+    /// Sets the red, green and blue values shown for a named color, or for its equivalent
+    /// [Color::Ansi256] color, any other color is left as is, as it's never restyled.
+    pub fn color(mut self, color: Color, rgb: (u8, u8, u8)) -> Palette {
+        if let Some(index) = named_index(&color) {
+            self.colors[index] = rgb;
+        }
+        self
+    }
+
+    /// Returns the red, green and blue values this palette shows for the given color.
+    pub(crate) fn rgb(&self, color: &Color) -> (u8, u8, u8) {
+        match named_index(color) {
+            Some(index) => self.colors[index],
+            None => color.to_rgb(),
+        }
+    }
+
+    /// Returns the stylesheet defining every CSS class written by
+    /// [Colorizer::apply_html_classes] and [Colorized::to_html_classes] with the colors of this
+    /// palette.
+    pub fn stylesheet(&self) -> String {
+        let mut output = String::new();
+        self.write_stylesheet(&mut output).expect("Writing into a String never fails");
+        output
+    }
+
+    /// Writes the stylesheet of this palette into a [core::fmt::Write], which is the same as
+    /// writing the result of [Palette::stylesheet], but without creating any [String].
+    pub fn write_stylesheet<Writer: Write + ?Sized>(&self, writer: &mut Writer) -> core::fmt::Result {
+        writer.write_str(".sc-fg{color:var(--sc-fg)}\n.sc-bg{background-color:var(--sc-bg)}\n")?;
+        for (class, (r, g, b)) in NAMED_CLASSES.iter().zip(self.colors) {
+            writeln!(writer, ".sc-fg-{class}{{--sc-fg:#{r:02x}{g:02x}{b:02x};color:#{r:02x}{g:02x}{b:02x}}}")?;
+            writeln!(writer, ".sc-bg-{class}{{--sc-bg:#{r:02x}{g:02x}{b:02x};background-color:#{r:02x}{g:02x}{b:02x}}}")?;
+        }
+        writer.write_str(".sc-bold{font-weight:bold}\n.sc-dimmed{opacity:0.5}\n.sc-italic{font-style:italic}\n")?;
+        writer.write_str(".sc-hidden{visibility:hidden}\n")?;
+        // Every combination of decorations needs its own rule, as they are set by a single property.
+        let decorations = [("underline", "underline"), ("strikethrough", "line-through"), ("blink", "blink")];
+        for combination in 1..(1 << decorations.len()) {
+            let combined = || decorations.iter().enumerate()
+                .filter(move |(index, _)| combination & (1 << index) != 0)
+                .map(|(_, decoration)| decoration);
+            for (class, _) in combined() {
+                write!(writer, ".sc-{class}")?;
+            }
+            writer.write_str("{text-decoration-line:")?;
+            let mut lines = Separated { writer: &mut *writer, separator: ' ', is_first: true };
+            for (_, line) in combined() {
+                lines.push(line)?;
+            }
+            writer.write_str("}\n")?;
+        }
+        writer.write_str(".sc-reversed{color:var(--sc-bg,Canvas);background-color:var(--sc-fg,CanvasText)}\n")
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::new()
+    }
+}
+
+/// Names of the 16 named colors within CSS classes, in the same order as [NAMED_COLORS].
+const NAMED_CLASSES: [&str; 16] = ["black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    "bright-black", "bright-red", "bright-green", "bright-yellow", "bright-blue", "bright-magenta",
+    "bright-cyan", "bright-white"];
+
+/// Returns the index within [NAMED_COLORS] of a named color or its equivalent [Color::Ansi256].
+fn named_index(color: &Color) -> Option<usize> {
+    match color {
+        Color::Ansi256(index @ 0..=15) => Some(*index as usize),
+        color => NAMED_COLORS.iter().position(|named| named == color),
+    }
+}
+
+/// Returns the name of a style within CSS classes, or none for [Styles::Clear].
+fn style_class(style: &Styles) -> Option<&'static str> {
+    match style {
+        Styles::Clear => None,
+        Styles::Bold => Some("bold"),
+        Styles::Dimmed => Some("dimmed"),
+        Styles::Underline => Some("underline"),
+        Styles::Reversed => Some("reversed"),
+        Styles::Italic => Some("italic"),
+        Styles::Blink => Some("blink"),
+        Styles::Hidden => Some("hidden"),
+        Styles::Strikethrough => Some("strikethrough"),
+    }
+}
+
+/// Writes the CSS declarations showing the colors and styles of a [Colorizer], separated by `;`.
+///
+/// When reversed, the foreground and background colors are swapped, using the colors of the page
+/// in place of the ones the [Colorizer] doesn't set.
+fn write_css<Writer: Write + ?Sized>(writer: &mut Writer, colorizer: &Colorizer) -> core::fmt::Result {
+    let mut declarations = Separated { writer, separator: ';', is_first: true };
+    let (foreground, background) = match colorizer.has_style(&Styles::Reversed) {
+        true => (Some(colorizer.background.map_or(CssColor::Canvas, CssColor::Color)),
+                 Some(colorizer.foreground.map_or(CssColor::CanvasText, CssColor::Color))),
+        false => (colorizer.foreground.map(CssColor::Color), colorizer.background.map(CssColor::Color)),
+    };
+    if let Some(foreground) = foreground {
+        declarations.push(format_args!("color:{foreground}"))?;
+    }
+    if let Some(background) = background {
+        declarations.push(format_args!("background-color:{background}"))?;
+    }
+    if colorizer.has_style(&Styles::Bold) {
+        declarations.push("font-weight:bold")?;
+    }
+    if colorizer.has_style(&Styles::Dimmed) {
+        declarations.push("opacity:0.5")?;
+    }
+    if colorizer.has_style(&Styles::Italic) {
+        declarations.push("font-style:italic")?;
+    }
+    let decorations = [(Styles::Underline, "underline"), (Styles::Strikethrough, "line-through"), (Styles::Blink, "blink")];
+    let mut decorations = decorations.iter().filter(|(style, _)| colorizer.has_style(style)).map(|(_, line)| *line);
+    if let Some(first_decoration) = decorations.next() {
+        declarations.push(format_args!("text-decoration:{first_decoration}"))?;
+        for decoration in decorations {
+            declarations.writer.write_char(' ')?;
+            declarations.writer.write_str(decoration)?;
+        }
+    }
+    if colorizer.has_style(&Styles::Hidden) {
+        declarations.push("visibility:hidden")?;
+    }
+    Ok(())
+}
+
+/// Writes the given text escaping the characters that have a special meaning in HTML, as well as
+/// in XML.
+pub(crate) fn write_escaped<Writer: Write + ?Sized>(writer: &mut Writer, text: &str) -> core::fmt::Result {
+    let mut written = 0;
+    for (offset, character) in text.char_indices() {
+        let escaped = match character {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' => "&quot;",
+            '\'' => "&#39;",
+            _ => continue,
+        };
+        writer.write_str(&text[written..offset])?;
+        writer.write_str(escaped)?;
+        written = offset + 1;
+    }
+    writer.write_str(&text[written..])
+}
+
+/// Color of a CSS declaration, either an exact one or one of the colors of the page.
+#[derive(Clone, Copy)]
+enum CssColor {
+    Color(Color),
+    Canvas,
+    CanvasText,
+}
+
+impl Display for CssColor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CssColor::Color(color) => Hex(color.to_rgb()).fmt(f),
+            CssColor::Canvas => f.write_str("Canvas"),
+            CssColor::CanvasText => f.write_str("CanvasText"),
+        }
+    }
+}
+
+/// Writes red, green and blue values as a hexadecimal color.
+pub(crate) struct Hex(pub(crate) (u8, u8, u8));
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (r, g, b) = self.0;
+        write!(f, "#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+/// Writes items separated by the given character, such as CSS declarations or classes.
+struct Separated<'writer, Writer: Write + ?Sized> {
+    writer: &'writer mut Writer,
+    separator: char,
+    is_first: bool,
+}
+
+impl<Writer: Write + ?Sized> Separated<'_, Writer> {
+    fn push<Item: Display>(&mut self, item: Item) -> core::fmt::Result {
+        if !self.is_first {
+            self.writer.write_char(self.separator)?;
+        }
+        self.is_first = false;
+        write!(self.writer, "{item}")
+    }
+}
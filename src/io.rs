@@ -0,0 +1,30 @@
+use core::fmt::Write;
+
+/// Adapts a [std::io::Write] into a [core::fmt::Write], keeping the IO error that made a write
+/// fail, as [core::fmt::Error] can't hold it.
+struct IoWriter<'writer, Writer: std::io::Write + ?Sized> {
+    writer: &'writer mut Writer,
+    error: Option<std::io::Error>,
+}
+
+impl<Writer: std::io::Write + ?Sized> Write for IoWriter<'_, Writer> {
+    fn write_str(&mut self, string: &str) -> core::fmt::Result {
+        self.writer.write_all(string.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            core::fmt::Error
+        })
+    }
+}
+
+/// Runs a function writing into a [core::fmt::Write] over the given [std::io::Write], returning
+/// the IO error that made it fail, if any.
+pub(crate) fn write_through<Writer: std::io::Write + ?Sized>(
+    writer: &mut Writer,
+    write: impl FnOnce(&mut dyn Write) -> core::fmt::Result,
+) -> std::io::Result<()> {
+    let mut io_writer = IoWriter { writer, error: None };
+    match write(&mut io_writer) {
+        Ok(()) => Ok(()),
+        Err(_) => Err(io_writer.error.unwrap_or_else(|| std::io::Error::other("formatter error"))),
+    }
+}
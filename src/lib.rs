@@ -6,10 +6,10 @@
 //! [![GitHub License](https://img.shields.io/github/license/JorgeRicoVivas/string_colorization)](https://github.com/JorgeRicoVivas/string_colorization?tab=CC0-1.0-1-ov-file)
 //! > *You are reading the documentation for string_colorization version 1.0.0*
 //!
-//! Abstracts colorizing string from the [colored] crate by giving a struct [Colorizer] combining
-//! foreground, background and stylizations to strings that can be applied later, and then uses them
-//! on the [colorize] function to allow you to colorize a string given a series substring and
-//! colorizers, for example, this code prints:
+//! Abstracts colorizing strings by giving a struct [Colorizer] combining foreground, background and
+//! stylizations to strings that can be applied later, and then uses them on the [colorize] function
+//! to allow you to colorize a string given a series substring and colorizers, for example, this
+//! code prints:
 //! *<span style="background-color:lightgray">
 //! <span style="color:red">R</span>
 //! <span style="color:orange">a</span>
@@ -20,8 +20,6 @@
 //! <span style="color:white">w</span>
 //! </span>'*:
 //! ```rust
-//! colored::control::set_override(true); // Forces colorization,
-//!                                       // this won't be necessary in your code.
 //! use string_colorization::{background, foreground};
 //!
 //! let rainbow = "Rainbow";
@@ -41,7 +39,7 @@
 //!                                           // lettering, if not indicated, it wouldn't colorize
 //!                                           // the letter 'n', leaving it as plain.
 //! println!("{colored_rainbow}");  //Prints Rainbow with colors
-//! assert_eq!(colored_rainbow, r"[31m[48;2;200;200;200mR[0m[31m[0m[38;2;255;160;0m[48;2;200;200;200ma[0m[38;2;255;160;0m[0m[33m[48;2;200;200;200mi[0m[33m[0m[32m[48;2;200;200;200mn[0m[32m[0m[34m[48;2;200;200;200mb[0m[34m[0m[35m[48;2;200;200;200mo[0m[35m[0m[37m[48;2;200;200;200mw[0m[37m[0m");
+//! assert_eq!(colored_rainbow, r"[31;48;2;200;200;200mR[38;2;255;160;0ma[33mi[32mn[34mb[35mo[37mw[0m");
 //! ```
 //!
 //! If one of the rule's substring is a reference to another string different
@@ -49,8 +47,6 @@
 //! code prints *'<span style="color:red">Red</span>, no red'*:
 //!
 //! ``` rust
-//! colored::control::set_override(true); // Forces colorization,
-//!                                       // this won't be necessary in your code.
 //! use string_colorization::foreground;
 //!
 //! let string_to_colorize = "Red, no red";
@@ -66,16 +62,156 @@
 //! assert_eq!(colorized_string, r"[31mRed[0m, no red");
 //! ```
 //!
+//! The escape sequences are written by this crate itself, so it only needs `alloc` and can be
+//! used on `no_std` targets, while enabling the `colored` feature adds conversions from and into
+//! the types of the [colored](https://docs.rs/colored) crate, the `regex` feature allows colorizing
+//! the matches of regular expressions, the `std` feature allows writing colorized text straight
+//! into a `std::io::Write`, and the `macros` feature adds the `cformat!` and `cwrite!` macros,
+//! which check the tags of a [markup](parse_markup) while compiling.
+//!
 //! Find more information and examples in the function [colorize] and the struct [Colorizer].
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use alloc::format;
-use alloc::string::{String, ToString};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::string::String;
 use alloc::vec::Vec;
-use core::ops::Add;
+use core::fmt::Write;
+use core::ops::{Add, Range};
+
+pub use ansi::{colorize_ansi, colorize_ansi_lazy, parse_ansi, strip_ansi, visible_width};
+pub use color::{Color, Styles};
+pub use colorized::Colorized;
+pub use git::GitColorError;
+pub use html::Palette;
+pub use literal::Literals;
+pub use ls_colors::{FileKind, LsColors, LsColorsError};
+pub use markup::{MarkupError, parse_markup};
+pub use merge::MergeStrategy;
+pub use render::RenderOptions;
+pub use rule::{Rule, RuleError};
+pub use sgr::{RESET, Sgr};
+pub use spec::SpecError;
+pub use styled::StyledString;
+pub use support::ColorSupport;
+pub use svg::SvgOptions;
+pub use terminal::TerminalProfile;
+
+mod ansi;
+mod color;
+mod colorized;
+mod git;
+mod html;
+#[cfg(feature = "std")]
+mod io;
+mod literal;
+mod ls_colors;
+mod markup;
+mod merge;
+mod render;
+mod rule;
+mod sgr;
+mod spec;
+mod styled;
+mod support;
+mod svg;
+mod terminal;
+
+/// Items used by the code the macros of the `macros` feature expand to, which aren't part of the
+/// public API.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::format;
+    pub use string_colorization_macros::{cformat, cwrite};
+
+    pub use crate::sgr::Transition;
+    use crate::Colorizer;
+
+    /// Returns whether a name within a tag is a color or a style, just like [parse_markup](crate::parse_markup)
+    /// understands it.
+    pub const fn is_tag_name(name: &str) -> bool {
+        crate::markup::parse_tag(name).is_ok()
+    }
 
-use colored::*;
+    /// Returns the [Colorizer] of a tag, which is plain when any of its names is unknown, as the
+    /// names are already checked through [is_tag_name], failing to compile on their own.
+    pub const fn tag_colorizer(tag: &str) -> Colorizer {
+        match crate::markup::parse_tag(tag) {
+            Ok(colorizer) => colorizer,
+            Err(_) => Colorizer::new(),
+        }
+    }
+}
+
+/// Creates a [String](alloc::string::String) just like [format!](alloc::format) does, where the
+/// format string is written in the tag markup of [parse_markup], such as `<green>ok</>`, whose tags
+/// are checked while compiling and turned into the escape sequences of constant [Colorizer]s, this
+/// is only available through the `macros` feature:
+///
+/// ```rust
+/// use string_colorization::cformat;
+///
+/// let items = 3;
+/// assert_eq!(cformat!("<green>ok</> {} items", items), "\x1b[32mok\x1b[0m 3 items");
+/// assert_eq!(cformat!("<bold>{items} <on_blue>new</></bold>"), "\x1b[1m3 \x1b[44mnew\x1b[0m");
+/// assert_eq!(cformat!(r"\<{}>", "tag"), "<tag>");
+/// ```
+///
+/// Just like [StyledString::render], only the colors and styles that change are written between
+/// two colorizations, so it writes the same as rendering the result of [parse_markup]:
+///
+/// ```rust
+/// use string_colorization::cformat;
+///
+/// let markup = "<bold>3 <on_blue>new</></bold>";
+/// assert_eq!(cformat!("<bold>3 <on_blue>new</></bold>"), string_colorization::parse_markup(markup).unwrap().render());
+/// ```
+///
+/// Placeholders are written as in [format!](alloc::format), even when they have a `<` such as
+/// `{:<5}`, and a `\` before a brace writes it as is, just like `{{` and `}}` do:
+///
+/// ```rust
+/// use string_colorization::cformat;
+///
+/// assert_eq!(cformat!("<green>{:<5}</>|", 1), "\x1b[32m1    \x1b[0m|");
+/// assert_eq!(cformat!(r"{{<red>\{</>}}"), "{\x1b[31m{\x1b[0m}");
+/// ```
+///
+/// Since the tags are checked while compiling, an unknown or unclosed tag doesn't compile:
+///
+/// ```rust,compile_fail
+/// let _ = string_colorization::cformat!("<purple>ok</>");
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! cformat {
+    ($($arguments:tt)*) => {
+        $crate::__private::cformat!($crate; $($arguments)*)
+    };
+}
+
+/// Writes into a writer just like [write!] does, where the format string is written in the tag
+/// markup of [parse_markup], see [cformat!], this is only available through the `macros` feature:
+///
+/// ```rust
+/// use core::fmt::Write;
+/// use string_colorization::cwrite;
+///
+/// let mut output = String::new();
+/// cwrite!(output, "<red,bold>error</>: {}", "not found").unwrap();
+/// assert_eq!(output, "\x1b[1;31merror\x1b[0m: not found");
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! cwrite {
+    ($($arguments:tt)*) => {
+        $crate::__private::cwrite!($crate; $($arguments)*)
+    };
+}
 
 macro_rules! make_colors {
         ($function:ident $($color:ident),*) => {
@@ -84,7 +220,7 @@ macro_rules! make_colors {
                 #[doc = stringify!($function)]
                 #[doc = " colorizer"]
                 #[allow(non_upper_case_globals)]
-                pub const $color:Colorizer=Colorizer::new().$function(colored::Color::$color);
+                pub const $color:Colorizer=Colorizer::new().$function($crate::Color::$color);
             )*
         };
     }
@@ -95,8 +231,35 @@ pub mod foreground {
 
     make_colors! {foreground Black, Red, Green, Yellow, Blue, Magenta, Cyan, White, BrightBlack, BrightRed, BrightGreen, BrightYellow, BrightBlue, BrightMagenta, BrightCyan, BrightWhite}
 
+    /// Creates a foreground [Colorizer] which will set the lettering of some text according to the
+    /// Red, Green and Blue values given
     pub const fn true_color(red: u8, green: u8, blue: u8) -> Colorizer {
-        Colorizer::new().foreground(colored::Color::TrueColor { r: red, g: green, b: blue })
+        Colorizer::new().foreground(crate::Color::TrueColor { r: red, g: green, b: blue })
+    }
+
+    /// Creates a foreground [Colorizer] which will set the lettering of some text to the given
+    /// color of the 256 colors palette, for terminals lacking true colors:
+    ///
+    /// ```rust
+    /// use string_colorization::foreground;
+    ///
+    /// assert_eq!(foreground::ansi256(208).apply("Orange"), "\x1b[38;5;208mOrange\x1b[0m");
+    /// ```
+    pub const fn ansi256(index: u8) -> Colorizer {
+        Colorizer::new().foreground(crate::Color::Ansi256(index))
+    }
+
+    /// Creates a foreground [Colorizer] which will set the lettering of some text to the color of
+    /// the 6x6x6 cube of the 256 colors palette with the given levels, see [Color::cube](crate::Color::cube).
+    pub const fn cube(red: u8, green: u8, blue: u8) -> Colorizer {
+        Colorizer::new().foreground(crate::Color::cube(red, green, blue))
+    }
+
+    /// Creates a foreground [Colorizer] which will set the lettering of some text to the color of
+    /// the grayscale ramp of the 256 colors palette with the given level, see
+    /// [Color::grayscale](crate::Color::grayscale).
+    pub const fn grayscale(level: u8) -> Colorizer {
+        Colorizer::new().foreground(crate::Color::grayscale(level))
     }
 }
 
@@ -110,7 +273,32 @@ pub mod background {
     /// Creates a background [Colorizer] which will set the background of some text according to the
     /// Red, Green and Blue values given
     pub const fn true_color(red: u8, green: u8, blue: u8) -> Colorizer {
-        Colorizer::new().background(colored::Color::TrueColor { r: red, g: green, b: blue })
+        Colorizer::new().background(crate::Color::TrueColor { r: red, g: green, b: blue })
+    }
+
+    /// Creates a background [Colorizer] which will set the background of some text to the given
+    /// color of the 256 colors palette, for terminals lacking true colors:
+    ///
+    /// ```rust
+    /// use string_colorization::background;
+    ///
+    /// assert_eq!(background::ansi256(208).apply("Orange"), "\x1b[48;5;208mOrange\x1b[0m");
+    /// ```
+    pub const fn ansi256(index: u8) -> Colorizer {
+        Colorizer::new().background(crate::Color::Ansi256(index))
+    }
+
+    /// Creates a background [Colorizer] which will set the background of some text to the color of
+    /// the 6x6x6 cube of the 256 colors palette with the given levels, see [Color::cube](crate::Color::cube).
+    pub const fn cube(red: u8, green: u8, blue: u8) -> Colorizer {
+        Colorizer::new().background(crate::Color::cube(red, green, blue))
+    }
+
+    /// Creates a background [Colorizer] which will set the background of some text to the color of
+    /// the grayscale ramp of the 256 colors palette with the given level, see
+    /// [Color::grayscale](crate::Color::grayscale).
+    pub const fn grayscale(level: u8) -> Colorizer {
+        Colorizer::new().background(crate::Color::grayscale(level))
     }
 }
 
@@ -124,7 +312,7 @@ pub mod style {
                 #[doc = stringify!($style)]
                 #[doc = "styled colorizer"]
                 #[allow(non_upper_case_globals)]
-                pub const $style:Colorizer=Colorizer::new().style(colored::Styles::$style);
+                pub const $style:Colorizer=Colorizer::new().style($crate::Styles::$style);
             )*
         };
     }
@@ -141,8 +329,6 @@ pub struct Colorizer {
     /// '<span style="color:red">Red letters!</span>', like:
     ///
     /// ```rust
-    /// colored::control::set_override(true); // Forces colorization,
-    ///                                       // this won't be necessary in your code.
     /// use string_colorization::foreground;
     ///
     /// let red_foreground = foreground::Red.apply("Red foreground");
@@ -156,8 +342,6 @@ pub struct Colorizer {
     /// '<span style="background-color:red;">Red background!</span>', like:
     ///
     /// ```rust
-    /// colored::control::set_override(true); // Forces colorization,
-    ///                                       // this won't be necessary in your code.
     /// use string_colorization::background;
     ///
     /// let red_background = background::Red.apply("Red background");
@@ -171,13 +355,11 @@ pub struct Colorizer {
     /// italic***', like:
     ///
     /// ```rust
-    /// colored::control::set_override(true); // Forces colorization,
-    ///                                       // this won't be necessary in your code.
     /// use string_colorization::style;
     ///
     /// let bold_and_italic = (style::Italic+style::Bold).apply("Italic and bold");
     /// println!("{bold_and_italic}");
-    /// assert_eq!("[3m[1mItalic and bold[0m[3m[0m", bold_and_italic);
+    /// assert_eq!("[1;3mItalic and bold[0m", bold_and_italic);
     /// ```
     style_const: Option<u16>,
 }
@@ -208,10 +390,7 @@ const fn sytle_to_index(style: &Styles) -> usize {
 /// - Example: This results into a string with a blue background and green lettering:
 ///
 /// ```rust
-/// use colored::Color;
-/// use string_colorization::Colorizer;
-/// colored::control::set_override(true); //Forces colorization
-///                                       //this won't be necessary in your code.
+/// use string_colorization::{Color, Colorizer};
 ///
 /// let blue_background = Colorizer::new().background(Color::Blue);
 /// let green_foreground = Colorizer::new().foreground(Color::Green);
@@ -219,18 +398,17 @@ const fn sytle_to_index(style: &Styles) -> usize {
 /// let output_string = blue_bg_and_green_fg.apply("Blue background with green letters!");
 /// println!("{output_string}"); //Prints some text with Blue background and green letters
 ///
-/// assert_eq!(output_string, "[32m[44mBlue background with green letters![0m[32m[0m");
+/// assert_eq!(output_string, "[32;44mBlue background with green letters![0m");
 /// let manually_created = Colorizer::new().background(Color::Blue).foreground(Color::Green);
 /// assert_eq!(manually_created, blue_bg_and_green_fg);
 /// ```
 ///
 /// - This makes much more comfortable to use the constants colorizers from the [foreground],
-/// [background] and [style] modules, for example, the blue background and green lettering
-/// [Colorizer] could also have been created this way:
+///   [background] and [style] modules, for example, the blue background and green lettering
+///   [Colorizer] could also have been created this way:
 ///
 /// ```rust
-/// use colored::Color;
-/// use string_colorization::{background, Colorizer, foreground};
+/// use string_colorization::{background, Color, Colorizer, foreground};
 ///
 /// let applying_sum = background::Blue+foreground::Green;
 /// let manually_created = Colorizer::new().background(Color::Blue).foreground(Color::Green);
@@ -238,30 +416,27 @@ const fn sytle_to_index(style: &Styles) -> usize {
 /// ```
 ///
 /// - When summing two colorizers, the second one has precedence, for example, summing one with blue
-/// letters to one with green letters results into a colorizer with just green letters:
+///   letters to one with green letters results into a colorizer with just green letters:
 ///
 /// ```rust
-/// use colored::Color;
-/// use string_colorization::{Colorizer, foreground};
+/// use string_colorization::{Color, Colorizer, foreground};
 ///
 /// let green_from_blue_and_green_sum = foreground::Blue+foreground::Green;
 /// assert_eq!(green_from_blue_and_green_sum, foreground::Green);
 /// ```
 ///
 /// - The reason for making the second one to have precedence over the first one instead of the first
-/// one is as it should result in the same order as when using the builder pattern, where applying
-/// [Colorizer::foreground] to [Color::Blue] and then to [Color::Green], results into just Green
-/// coloring:
+///   one is as it should result in the same order as when using the builder pattern, where applying
+///   [Colorizer::foreground] to [Color::Blue] and then to [Color::Green], results into just Green
+///   coloring:
 ///
 /// ```rust
-/// use colored::Color;
-/// use string_colorization::{Colorizer, foreground};
+/// use string_colorization::{Color, Colorizer, foreground};
 ///
 /// let applying_sum = foreground::Blue+foreground::Green;
 /// let manually_created = Colorizer::new().foreground(Color::Blue).foreground(Color::Green);
 /// assert_eq!(applying_sum, manually_created);
 /// ```
-
 impl Add for Colorizer {
 
     /// Summing two [Colorizer]s results into a new [Colorizer].
@@ -286,10 +461,7 @@ impl Colorizer {
     /// '<span style="color:red">Red letters!</span>':
     ///
     /// ```rust
-    /// colored::control::set_override(true); // Forces colorization,
-    ///                                       // this won't be necessary in your code.
-    /// use string_colorization::Colorizer;
-    /// use colored::Color;
+    /// use string_colorization::{Color, Colorizer};
     ///
     /// let red_foreground = Colorizer::new().foreground(Color::Red).apply("Red foreground");
     /// println!("{red_foreground}");
@@ -307,10 +479,7 @@ impl Colorizer {
     /// '<span style="background-color:red;">Red background!</span>':
     ///
     /// ```rust
-    /// colored::control::set_override(true); // Forces colorization,
-    ///                                       // this won't be necessary in your code.
-    /// use string_colorization::Colorizer;
-    /// use colored::Color;
+    /// use string_colorization::{Color, Colorizer};
     ///
     /// let red_background = Colorizer::new().background(Color::Red).apply("Red background");
     /// println!("{red_background}");
@@ -327,15 +496,12 @@ impl Colorizer {
     /// italic***':
     ///
     /// ```rust
-    /// colored::control::set_override(true); // Forces colorization,
-    ///                                       // this won't be necessary in your code.
-    /// use string_colorization::Colorizer;
-    /// use colored::{Color, Styles};
+    /// use string_colorization::{Colorizer, Styles};
     ///
     /// let bold_and_italic = Colorizer::new().style(Styles::Italic).style(Styles::Bold)
     ///         .apply("Italic and bold");
     /// println!("{bold_and_italic}");
-    /// assert_eq!("[3m[1mItalic and bold[0m[3m[0m", bold_and_italic);
+    /// assert_eq!("[1;3mItalic and bold[0m", bold_and_italic);
     /// ```
     pub const fn style(mut self, style: Styles) -> Colorizer {
         match style {
@@ -363,10 +529,7 @@ impl Colorizer {
     /// - Example: This results into a string with a blue background and green lettering:
     ///
     /// ```rust
-    /// use colored::Color;
-    /// use string_colorization::Colorizer;
-    /// colored::control::set_override(true); //Forces colorization
-    ///                                       //this won't be necessary in your code.
+    /// use string_colorization::{Color, Colorizer};
     ///
     /// let blue_background = Colorizer::new().background(Color::Blue);
     /// let green_foreground = Colorizer::new().foreground(Color::Green);
@@ -374,18 +537,17 @@ impl Colorizer {
     /// let output_string = blue_bg_and_green_fg.apply("Blue background with green letters!");
     /// println!("{output_string}"); //Prints some text with Blue background and green letters
     ///
-    /// assert_eq!(output_string, "[32m[44mBlue background with green letters![0m[32m[0m");
+    /// assert_eq!(output_string, "[32;44mBlue background with green letters![0m");
     /// let manually_created = Colorizer::new().background(Color::Blue).foreground(Color::Green);
     /// assert_eq!(manually_created, blue_bg_and_green_fg);
     /// ```
     ///
     /// - This makes much more comfortable to use the constants colorizers from the [foreground],
-    /// [background] and [style] modules, for example, the blue background and green lettering
-    /// [Colorizer] could also have been created this way:
+    ///   [background] and [style] modules, for example, the blue background and green lettering
+    ///   [Colorizer] could also have been created this way:
     ///
     /// ```rust
-    /// use colored::Color;
-    /// use string_colorization::{background, Colorizer, foreground};
+    /// use string_colorization::{background, Color, Colorizer, foreground};
     ///
     /// let applying_sum = background::Blue.join_with(foreground::Green);
     /// let manually_created = Colorizer::new().background(Color::Blue).foreground(Color::Green);
@@ -393,29 +555,29 @@ impl Colorizer {
     /// ```
     ///
     /// - When summing two colorizers, the second one has precedence, for example, summing one with blue
-    /// letters to one with green letters results into a colorizer with just green letters:
+    ///   letters to one with green letters results into a colorizer with just green letters:
     ///
     /// ```rust
-    /// use colored::Color;
-    /// use string_colorization::{Colorizer, foreground};
+    /// use string_colorization::{Color, Colorizer, foreground};
     ///
     /// let green_from_blue_and_green_sum = foreground::Blue.join_with(foreground::Green);
     /// assert_eq!(green_from_blue_and_green_sum, foreground::Green);
     /// ```
     ///
     /// - The reason for making the second one to have precedence over the first one instead of the first
-    /// one is as it should result in the same order as when using the builder pattern, where applying
-    /// [Colorizer::foreground] to [Color::Blue] and then to [Color::Green], results into just Green
-    /// coloring:
+    ///   one is as it should result in the same order as when using the builder pattern, where applying
+    ///   [Colorizer::foreground] to [Color::Blue] and then to [Color::Green], results into just Green
+    ///   coloring:
     ///
     /// ```rust
-    /// use colored::Color;
-    /// use string_colorization::{Colorizer, foreground};
+    /// use string_colorization::{Color, Colorizer, foreground};
     ///
     /// let applying_sum = foreground::Blue.join_with(foreground::Green);
     /// let manually_created = Colorizer::new().foreground(Color::Blue).foreground(Color::Green);
     /// assert_eq!(applying_sum, manually_created);
     /// ```
+    ///
+    /// - To join them differently, such as letting the first one win, use [Colorizer::join_using].
     pub const fn join_with(mut self, new: Self) -> Self {
         if new.foreground.is_some() {
             self.foreground = new.foreground;
@@ -450,18 +612,20 @@ impl Colorizer {
     }
 
     /// Transforms all the styles in [Colorizer::style_const] to [Styles].
-    fn get_styles(&self) -> impl IntoIterator<Item=Styles> + '_ {
-        STYLES.into_iter().filter(|style|
-            self.style_const.is_some() && (self.style_const.unwrap() & (1 << sytle_to_index(&style))) == 1 << sytle_to_index(&style)
-        )
+    fn get_styles(&self) -> impl Iterator<Item=Styles> + '_ {
+        STYLES.into_iter().filter(|style| self.has_style(style))
+    }
+
+    /// Checks whether the given style is in [Colorizer::style_const].
+    fn has_style(&self, style: &Styles) -> bool {
+        self.style_const.is_some() && (self.style_const.unwrap() & (1 << sytle_to_index(style))) == 1 << sytle_to_index(style)
     }
 
     /// Adds the following styles to this [Colorizer], meaning this is the same as applying
     /// [Colorizer::style] on all of them, for example, both here result in the same:
     ///
     /// ```rust
-    /// use colored::Styles;
-    /// use string_colorization::Colorizer;
+    /// use string_colorization::{Colorizer, Styles};
     ///
     /// let using_styles = Colorizer::new().styles([Styles::Bold, Styles::Italic]);
     /// let using_style = Colorizer::new().style(Styles::Bold).style(Styles::Italic);
@@ -475,10 +639,96 @@ impl Colorizer {
         self
     }
 
+    /// Returns the escape sequence that makes a terminal show text with the colors and styles of
+    /// this [Colorizer], see [Sgr] for more information.
+    pub const fn sgr(&self) -> Sgr<'_> {
+        Sgr { colorizer: self }
+    }
+
     /// Applies the foreground color, background color, and style to an owned copy of the input
     /// string, and the returns it after applying them, leaving the input intact.
+    ///
+    /// All of them are combined into a single escape sequence written before the text, followed
+    /// by a [RESET] after it, unless this [Colorizer] doesn't set anything, in which case the text
+    /// is returned as is:
+    ///
+    /// ```rust
+    /// use string_colorization::{Colorizer, foreground, style};
+    ///
+    /// let bold_red = (style::Bold + foreground::Red).apply("Bold and red");
+    /// assert_eq!(bold_red, "\x1b[1;31mBold and red\x1b[0m");
+    /// assert_eq!(Colorizer::new().apply("Plain"), "Plain");
+    /// ```
     pub fn apply(&self, input: &str) -> String {
-        let mut output = input.to_string();
+        let mut output = String::with_capacity(input.len());
+        self.write_to(&mut output, input).expect("Writing into a String never fails");
+        output
+    }
+
+    /// Applies this [Colorizer] just like [Colorizer::apply] does, but following the given
+    /// [RenderOptions], or the ones of a [ColorSupport], which turn its colors into the closest
+    /// ones that can be shown first, see [Colorizer::downgrade], or leave the text as is when
+    /// disabled:
+    ///
+    /// ```rust
+    /// use string_colorization::{ColorSupport, foreground, RenderOptions};
+    ///
+    /// let orange = foreground::true_color(255, 140, 10);
+    /// assert_eq!(orange.apply_with("Orange", ColorSupport::Ansi256), "\x1b[38;5;208mOrange\x1b[0m");
+    /// assert_eq!(orange.apply_with("Orange", ColorSupport::None), "Orange");
+    /// assert_eq!(orange.apply_with("Orange", RenderOptions { enabled: false, ..RenderOptions::new() }), "Orange");
+    /// ```
+    pub fn apply_with<Options: Into<RenderOptions>>(&self, input: &str, render_options: Options) -> String {
+        self.downgrade(render_options.into().color_support()).apply(input)
+    }
+
+    /// Writes the input string into a [core::fmt::Write] with this [Colorizer] applied, which is
+    /// the same as writing the result of [Colorizer::apply], but without creating any [String]:
+    ///
+    /// ```rust
+    /// use string_colorization::foreground;
+    ///
+    /// let mut output = String::from("Colorized: ");
+    /// foreground::Red.write_to(&mut output, "Red foreground").unwrap();
+    /// assert_eq!(output, "Colorized: \x1b[31mRed foreground\x1b[0m");
+    /// ```
+    pub fn write_to<Writer: Write + ?Sized>(&self, writer: &mut Writer, input: &str) -> core::fmt::Result {
+        if self.is_plain() {
+            return writer.write_str(input);
+        }
+        write!(writer, "{}{input}{RESET}", self.sgr())
+    }
+
+    /// Writes the input string into a [std::io::Write] with this [Colorizer] applied, this is the
+    /// same as [Colorizer::write_to], but for byte sinks such as files or
+    /// [stdout](std::io::stdout), and it's only available through the `std` feature:
+    ///
+    /// ```rust
+    /// use string_colorization::foreground;
+    ///
+    /// let mut output = Vec::new();
+    /// foreground::Red.write_to_io(&mut output, "Red foreground").unwrap();
+    /// assert_eq!(output, b"\x1b[31mRed foreground\x1b[0m");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to_io<Writer: std::io::Write + ?Sized>(&self, writer: &mut Writer, input: &str) -> std::io::Result<()> {
+        io::write_through(writer, |writer| self.write_to(writer, input))
+    }
+
+    /// Returns whether this [Colorizer] doesn't make any change to a text, this is, it has no
+    /// foreground, background nor any style that writes an escape sequence.
+    fn is_plain(&self) -> bool {
+        self.foreground.is_none() && self.background.is_none()
+            && self.get_styles().all(|style| style.code().is_none())
+    }
+
+    /// Applies this [Colorizer] through the [colored] crate, returning a [colored::ColoredString]
+    /// instead of a [String], this is only available through the `colored` feature.
+    #[cfg(feature = "colored")]
+    pub fn apply_colored(&self, input: &str) -> colored::ColoredString {
+        use colored::{ColoredString, Colorize};
+
+        let mut output = ColoredString::from(input);
         for style in self.get_styles() {
             let stylizer: fn(ColoredString) -> ColoredString = match style {
                 Styles::Clear => Colorize::clear,
@@ -491,45 +741,31 @@ impl Colorizer {
                 Styles::Hidden => Colorize::hidden,
                 Styles::Strikethrough => Colorize::strikethrough,
             };
-            output = stylizer(ColoredString::from(output)).to_string();
+            output = stylizer(output);
         }
         if let Some(background_color) = self.background {
-            output = output.on_color(background_color).to_string();
+            output = output.on_color(colored::Color::from(background_color));
         }
         if let Some(foreground_color) = self.foreground {
-            output = output.color(foreground_color).to_string();
+            output = output.color(colored::Color::from(foreground_color));
         }
         output
     }
 }
 
-/// Given a str, it returns the memory address it is located at, and then the final position in
-/// memory taken by this str
-fn mem_dir_of_string(string: &str) -> (usize, usize) {
-    let dir = unsafe { core::mem::transmute::<_, usize>(string.as_ptr()) };
-    (dir, dir + string.len())
-}
-
-/// Checks if range 1 contains the second one, being partially or completely, for example: 2..4
-/// completely contains 2..3, 2..4 and 3..4, while it partially contains ranges like 3..5 (in 3..4)
-/// or 1..3 (in 2..3), but does not contain nor partially nor completely a range like 10..20 or
-/// 4..5.
-fn range_contains_other(range_1_start: usize, range_1_end: usize, range_2_start: usize, range_2_end: usize) -> bool {
-    let res = range_2_end > range_1_start && range_2_start < range_1_end;
-    let res = res;
-    res
-}
-
 /// Colorizes every substring over a string and returns a [String] where every substring has been
 /// stylized according to these rules.
 ///
-/// * `input` - Text whose substrings we want to colorize.
+/// * `input` - Text whose substrings we want to colorize, when it's already colorized through
+///   escape sequences, use [colorize_ansi] instead.
 /// * `general_colorization` - Colorization to apply when no rule applies to a character.
-/// * `input_modifiers` - Iterator of substring and colorization pairs, this is: a rule, for every
-/// rule it searches for the substring over the original string, and then applies it's colorization
+/// * `input_modifiers` - Iterator of [Rule]s, such as substring and colorization pairs, for every
+///   rule it searches for the part of the original string it points to, and then applies it's
+///   colorization
 ///
 /// When two or more substrings inside the `input_modifiers` parameter are substring of the same
-/// characters, the last [Colorizer]s, for example, the following code prints:
+/// characters, the last [Colorizer]s take precedence, unless their [Rule::priority] says otherwise,
+/// and they are joined as their [Rule::merge] says, for example, the following code prints:
 /// *'<span style="color:red">R</span>
 /// <span style="color:orange">a</span>
 /// <span style="color:yellow">i</span>
@@ -539,8 +775,6 @@ fn range_contains_other(range_1_start: usize, range_1_end: usize, range_2_start:
 /// <span style="color:gray">w</span>'*
 ///
 /// ```rust
-/// colored::control::set_override(true); // Forces colorization,
-///                                       // this won't be necessary in your code.
 /// use string_colorization::foreground;
 ///
 /// let rainbow = "Rainbow";
@@ -559,16 +793,16 @@ fn range_contains_other(range_1_start: usize, range_1_end: usize, range_2_start:
 ///                                           // lettering, if not indicated, it wouldn't colorize
 ///                                           // the letter 'n', leaving it as plain.
 /// println!("{colored_rainbow}");  //Prints Rainbow with colors
-/// assert_eq!(colored_rainbow, r"[31mR[0m[38;2;255;160;0ma[0m[33mi[0m[32mn[0m[34mb[0m[35mo[0m[37mw[0m");
+/// assert_eq!(colored_rainbow, r"[31mR[38;2;255;160;0ma[33mi[32mn[34mb[35mo[37mw[0m");
 /// ```
 ///
 /// * *IMPORTANT NOTE*: If one of the rule's substring is a reference to another string different
-/// from the *input* argument, then the rule will just not be applied, for example, the following
-/// code prints *'<span style="color:red">Red</span>, no red'*:
+///   from the *input* argument, or any other [Rule] points outside of it, then the rule will just
+///   not be applied, for example, the following code prints
+///   *'<span style="color:red">Red</span>, no red'*, use [try_colorize] or
+///   [Colorized::rejected_rules] to find out about these rules instead:
 ///
 /// ``` rust
-/// colored::control::set_override(true); // Forces colorization,
-///                                       // this won't be necessary in your code.
 /// use string_colorization::foreground;
 ///
 /// let string_to_colorize = "Red, no red";
@@ -583,35 +817,169 @@ fn range_contains_other(range_1_start: usize, range_1_end: usize, range_2_start:
 /// println!("{colorized_string}"); //Prints 'Red' in red coloring and 'no red' without color.
 /// assert_eq!(colorized_string, r"[31mRed[0m, no red");
 /// ```
+pub fn colorize<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> String {
+    let mut output = String::with_capacity(input.len());
+    colorize_into(&mut output, input, general_colorization, input_modifiers)
+        .expect("Writing into a String never fails");
+    output
+}
+
+/// Colorizes every substring over a string just like [colorize] does, but following the given
+/// [RenderOptions], or the ones of a [ColorSupport] or [TerminalProfile], instead of writing every
+/// color as is, so the same text can be colorized differently for each output without any global
+/// state:
+///
+/// ```rust
+/// use string_colorization::{foreground, ColorSupport, RenderOptions, TerminalProfile};
+///
+/// let text = "Orange, no orange";
+/// let rules = [(&text[0..6], foreground::true_color(255, 140, 10))];
+/// assert_eq!(string_colorization::colorize_with(text, None, rules.clone(), ColorSupport::Ansi16),
+///     "\x1b[33mOrange\x1b[0m, no orange");
+/// assert_eq!(string_colorization::colorize_with(text, None, rules.clone(), RenderOptions { enabled: false, ..RenderOptions::new() }),
+///     "Orange, no orange");
+/// assert_eq!(string_colorization::colorize_with(text, None, rules.clone(), TerminalProfile::new().is_tty(false)),
+///     "Orange, no orange");
+/// ```
+pub fn colorize_with<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>, Options: Into<RenderOptions>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers, render_options: Options) -> String {
+    let mut output = String::with_capacity(input.len());
+    colorize_lazy(input, general_colorization, input_modifiers).render_options(render_options).write_to(&mut output)
+        .expect("Writing into a String never fails");
+    output
+}
+
+/// Colorizes every substring over a string just like [colorize] does, but where every rule joins
+/// its colorization over the ones of the rules with less precedence through the given
+/// [MergeStrategy], instead of [MergeStrategy::Layer], unless the rule sets its own one through
+/// [Rule::merge]:
+///
+/// ```rust
+/// use string_colorization::{background, foreground, MergeStrategy, Rule};
+///
+/// let text = "warning: unused";
+/// let colorized = string_colorization::colorize_using(text, None, [
+///     Rule::bytes(0..15, foreground::Yellow),
+///     Rule::bytes(0..7, foreground::Red + background::Black),
+///     Rule::bytes(9..15, foreground::Cyan).merge(MergeStrategy::Layer),
+/// ], MergeStrategy::FillMissing);
+/// assert_eq!(colorized, "\x1b[33;40mwarning\x1b[49m: \x1b[36munused\x1b[0m");
+/// ```
+pub fn colorize_using<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers, merge_strategy: MergeStrategy) -> String {
+    let (segments, rejected_rules) = resolve_segments(input, general_colorization, Vec::new(), input_modifiers, merge_strategy);
+    let colorized = Colorized { input: Cow::Borrowed(input), segments, sequences: Vec::new(), rejected_rules, render_options: RenderOptions::new() };
+    let mut output = String::with_capacity(input.len());
+    colorized.write_to(&mut output).expect("Writing into a String never fails");
+    output
+}
+
+/// Colorizes every substring over a string just like [colorize] does, but writing the result
+/// straight into a [core::fmt::Write] in a single pass, instead of returning a [String]:
+///
+/// ```rust
+/// use string_colorization::foreground;
+///
+/// let text = "Red, no red";
+/// let mut output = String::new();
+/// string_colorization::colorize_into(&mut output, text, None, [
+///     (&text[0..3], foreground::Red),
+/// ]).unwrap();
+/// assert_eq!(output, "\x1b[31mRed\x1b[0m, no red");
+/// ```
+pub fn colorize_into<'input, Writer: Write + ?Sized, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(writer: &mut Writer, input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> core::fmt::Result {
+    colorize_lazy(input, general_colorization, input_modifiers).write_to(writer)
+}
+
+/// Colorizes every substring over a string just like [colorize] does, but writing the result
+/// straight into a [std::io::Write], this is only available through the `std` feature:
+///
+/// ```rust
+/// use string_colorization::foreground;
+///
+/// let text = "Red, no red";
+/// let mut output = Vec::new();
+/// string_colorization::colorize_into_io(&mut output, text, None, [
+///     (&text[0..3], foreground::Red),
+/// ]).unwrap();
+/// assert_eq!(output, b"\x1b[31mRed\x1b[0m, no red");
+/// ```
+#[cfg(feature = "std")]
+pub fn colorize_into_io<'input, Writer: std::io::Write + ?Sized, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(writer: &mut Writer, input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> std::io::Result<()> {
+    io::write_through(writer, |writer| colorize_into(writer, input, general_colorization, input_modifiers))
+}
+
+/// Resolves which colorization applies to every substring of the input just like [colorize] does,
+/// but returns a [Colorized] that is only written when displayed, allowing to use it directly in
+/// [format_args!] or `println!`, as well as padding it as any other text:
+///
+/// ```rust
+/// use string_colorization::foreground;
+///
+/// let text = "Red, no red";
+/// let colorized = string_colorization::colorize_lazy(text, None, [
+///     (&text[0..3], foreground::Red),
+/// ]);
+/// assert_eq!(format!("[{colorized}]"), "[\x1b[31mRed\x1b[0m, no red]");
+/// assert_eq!(format!("[{colorized:>13}]"), "[  \x1b[31mRed\x1b[0m, no red]");
+/// ```
+pub fn colorize_lazy<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> Colorized<'input> {
+    let (segments, rejected_rules) = resolve_segments(input, general_colorization, Vec::new(), input_modifiers, MergeStrategy::Layer);
+    Colorized { input: Cow::Borrowed(input), segments, sequences: Vec::new(), rejected_rules, render_options: RenderOptions::new() }
+}
+
+/// Colorizes every substring over a string just like [colorize] does, but instead of ignoring the
+/// rules that don't point to a part of the input, it returns the [RuleError] of the first of them:
+///
+/// ```rust
+/// use string_colorization::{foreground, Rule, RuleError};
+///
+/// let text = "Red, no red";
+/// let copy = text.to_string();
+/// assert_eq!(string_colorization::try_colorize(text, None, [(&text[0..3], foreground::Red)]),
+///     Ok("\x1b[31mRed\x1b[0m, no red".to_string()));
+/// assert_eq!(string_colorization::try_colorize(text, None, [(&copy[0..3], foreground::Red)]),
+///     Err(RuleError::ForeignSlice { rule_index: 0 }));
+/// assert_eq!(string_colorization::try_colorize(text, None, [Rule::bytes(5..20, foreground::Red)]),
+///     Err(RuleError::InvalidRange { rule_index: 0, range: 5..20, len: 11 }));
+/// ```
+pub fn try_colorize<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> Result<String, RuleError> {
+    let colorized = colorize_lazy(input, general_colorization, input_modifiers);
+    if let Some(rule_error) = colorized.rejected_rules.first() {
+        return Err(rule_error.clone());
+    }
+    let mut output = String::with_capacity(input.len());
+    colorized.write_to(&mut output).expect("Writing into a String never fails");
+    Ok(output)
+}
 
-pub fn colorize<'input, Modifiers: IntoIterator<Item=(&'input str, Colorizer)>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> String {
-    if !colored::control::SHOULD_COLORIZE.should_colorize() {
-        return input.to_string();
+/// Splits the input into the ranges of bytes where the rules apply, sorted by their start, along
+/// with the [Colorizer] resulting from joining every rule applying to that range, and the errors
+/// of the rules that couldn't be applied.
+///
+/// The existing ranges are colorizations the input already has, which apply over the general
+/// colorization and beneath every rule, while the merge strategy is the one of the rules that
+/// don't set their own.
+fn resolve_segments<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(input: &str, general_colorization: Option<Colorizer>, existing_ranges: Vec<(Range<usize>, Colorizer)>, input_modifiers: Modifiers, merge_strategy: MergeStrategy) -> (Vec<(Range<usize>, Colorizer)>, Vec<RuleError>) {
+    let mut rejected_rules = Vec::new();
+    let mut prioritized_ranges = Vec::new();
+    let mut rule_ranges = Vec::new();
+    for (rule_index, rule) in input_modifiers.into_iter().enumerate() {
+        let rule = rule.into();
+        match rule.resolve(input, rule_index, &mut rule_ranges) {
+            Ok(()) => prioritized_ranges.extend(rule_ranges.drain(..)
+                .map(|(range, modifier)| (rule.priority, range, (modifier, rule.merge_strategy.unwrap_or(merge_strategy))))),
+            Err(rule_error) => {
+                rule_ranges.clear();
+                rejected_rules.push(rule_error);
+            }
+        }
     }
-    let (input_start, input_end) = mem_dir_of_string(input);
-    let input_len = input.len();
-    let input_modifiers = input_modifiers.into_iter();
-
-    let tail = if let Some(general_colorization) = general_colorization {
-        [(input, general_colorization)]
-    } else { [("", Colorizer::new())] };
-    let input_modifiers = tail.into_iter().chain(input_modifiers);
-
-    let ranges_and_modifiers = input_modifiers.into_iter()
-        .map(|(str_slice, value)| {
-            let (offset_start, offset_end) = mem_dir_of_string(str_slice);
-            (offset_start, offset_end, value)
-        })
-        .filter(|(offset_start, offset_end, _)| {
-            range_contains_other(*offset_start, *offset_end, input_start, input_end)
-        })
-        .map(|(offset_start, offset_end, value)| {
-            (
-                offset_start.checked_sub(input_start).unwrap_or(0).min(input_len),
-                offset_end.checked_sub(input_start).unwrap_or(0).min(input_len),
-                value
-            )
-        })
+    prioritized_ranges.sort_by_key(|(priority, _, _)| *priority);
+
+    let ranges_and_modifiers = general_colorization.map(|general_colorization| (0..input.len(), (general_colorization, MergeStrategy::Layer)))
+        .into_iter()
+        .chain(existing_ranges.into_iter().map(|(range, colorizer)| (range, (colorizer, MergeStrategy::Layer))))
+        .chain(prioritized_ranges.into_iter().map(|(_, range, modifier)| (range, modifier)))
+        .map(|(range, modifier)| (range.start, range.end, modifier))
         .filter(|(start, end, _)| end > start)
         .collect::<Vec<_>>();
 
@@ -622,27 +990,29 @@ pub fn colorize<'input, Modifiers: IntoIterator<Item=(&'input str, Colorizer)>>(
     bounds.sort();
     bounds.dedup();
 
-
-    let mut ranges_and_modifiers =
-        bounds.windows(2)
-            .map(|ran| (ran[0], ran[1]))
-            .map(|(start, end)| {
-                let mut colorization = Colorizer::new();
-                for found_colorizer in ranges_and_modifiers.iter().filter(|range_and_modifier|
-                    range_contains_other(start, end, range_and_modifier.0, range_and_modifier.1))
-                    .map(|(_, _, modifier)| modifier) {
-                    colorization = colorization.join_with(found_colorizer.clone());
-                }
-                (start, end, colorization)
-            })
-            .collect::<Vec<_>>();
-    ranges_and_modifiers.sort_by(|(start_1, _, _), (start_2, _, _)| start_1.cmp(start_2).reverse());
-
-    let mut output = input.to_string();
-    ranges_and_modifiers.into_iter()
-        .for_each(|(start, offset_end, modifier)| {
-            let modified = modifier.apply(&output[start..offset_end]);
-            output = format!("{}{}{}", &output[..start], modified, &output[offset_end..]);
-        });
-    output
-}
\ No newline at end of file
+    // Sweeps the bounds keeping the ranges containing the current segment, ordered by their
+    // precedence, this is, by their position within ranges_and_modifiers.
+    let mut by_start = (0..ranges_and_modifiers.len()).collect::<Vec<_>>();
+    by_start.sort_by_key(|index| ranges_and_modifiers[*index].0);
+    let mut by_end = (0..ranges_and_modifiers.len()).collect::<Vec<_>>();
+    by_end.sort_by_key(|index| ranges_and_modifiers[*index].1);
+    let (mut by_start, mut by_end) = (by_start.into_iter().peekable(), by_end.into_iter().peekable());
+    let mut active = BTreeSet::new();
+    let segments = bounds.windows(2)
+        .map(|ran| (ran[0], ran[1]))
+        .map(|(start, end)| {
+            while let Some(index) = by_end.next_if(|index| ranges_and_modifiers[*index].1 <= start) {
+                active.remove(&index);
+            }
+            while let Some(index) = by_start.next_if(|index| ranges_and_modifiers[*index].0 <= start) {
+                active.insert(index);
+            }
+            let colorization = active.iter().fold(Colorizer::new(), |colorization, index| {
+                let (_, _, (found_colorizer, merge_strategy)) = &ranges_and_modifiers[*index];
+                colorization.join_using(found_colorizer.clone(), *merge_strategy)
+            });
+            (start..end, colorization)
+        })
+        .collect();
+    (segments, rejected_rules)
+}
@@ -0,0 +1,221 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{Colorizer, Rule};
+
+/// Set of literal texts, or needles, to colorize on every place they appear within the input, each
+/// one with its own [Colorizer], which becomes a [Rule] once all needles have been added.
+///
+/// Every needle is searched at once in a single pass over the input, no matter how many of them
+/// there are, and they can optionally be matched ignoring case or only as whole words:
+///
+/// ```rust
+/// use string_colorization::{foreground, Literals};
+///
+/// let text = "Error: disk full, warning: error log rotated";
+/// let keywords = Literals::new()
+///     .needle("error", foreground::Red)
+///     .needle("warning", foreground::Yellow)
+///     .case_insensitive(true);
+/// let colorized = string_colorization::colorize(text, None, [keywords]);
+/// assert_eq!(colorized, "\x1b[31mError\x1b[0m: disk full, \x1b[33mwarning\x1b[0m: \x1b[31merror\x1b[0m log rotated");
+/// ```
+///
+/// When needles overlap, the [Colorizer] of the needle added later takes precedence, just like with
+/// [colorize](crate::colorize) rules.
+#[derive(Clone, Debug, Default)]
+pub struct Literals {
+    needles: Vec<(String, Colorizer)>,
+    case_insensitive: bool,
+    whole_word: bool,
+}
+
+impl Literals {
+    /// Creates a set of literals without any needle, which are matched with case and regardless of
+    /// words boundaries.
+    pub const fn new() -> Literals {
+        Literals { needles: Vec::new(), case_insensitive: false, whole_word: false }
+    }
+
+    /// Adds a needle to colorize with the given [Colorizer], empty needles never match.
+    pub fn needle<Needle: Into<String>>(mut self, needle: Needle, colorizer: Colorizer) -> Literals {
+        self.needles.push((needle.into(), colorizer));
+        self
+    }
+
+    /// Adds every needle with its [Colorizer], this is the same as applying [Literals::needle] on
+    /// all of them.
+    pub fn needles<Needle: Into<String>, Needles: IntoIterator<Item=(Needle, Colorizer)>>(mut self, needles: Needles) -> Literals {
+        for (needle, colorizer) in needles {
+            self = self.needle(needle, colorizer);
+        }
+        self
+    }
+
+    /// Sets whether needles match regardless of the case of letters, such as *'error'* matching
+    /// *'ERROR'* and *'Error'*.
+    pub const fn case_insensitive(mut self, case_insensitive: bool) -> Literals {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Sets whether needles only match whole words, this is, when they aren't preceded nor
+    /// followed by a letter, digit or underscore, such as *'log'* not matching *'logger'*:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, Literals};
+    ///
+    /// let text = "log the logger";
+    /// let log = Literals::new().needle("log", foreground::Cyan).whole_word(true);
+    /// let colorized = string_colorization::colorize(text, None, [log]);
+    /// assert_eq!(colorized, "\x1b[36mlog\x1b[0m the logger");
+    /// ```
+    pub const fn whole_word(mut self, whole_word: bool) -> Literals {
+        self.whole_word = whole_word;
+        self
+    }
+
+    /// Compiles the needles into the automaton that searches for them.
+    pub(crate) fn compile(self) -> LiteralMatcher {
+        let case_insensitive = self.case_insensitive;
+        let mut nodes = alloc::vec![Node::default()];
+        let mut colorizers = Vec::with_capacity(self.needles.len());
+        for (needle_index, (needle, colorizer)) in self.needles.into_iter().enumerate() {
+            colorizers.push(colorizer);
+            let mut folded_len = 0;
+            let mut node = 0;
+            for character in needle.chars().flat_map(|character| fold(character, case_insensitive)) {
+                folded_len += 1;
+                node = match nodes[node].transition(character) {
+                    Some(next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        let transitions = &mut nodes[node].transitions;
+                        let position = transitions.partition_point(|(other, _)| *other < character);
+                        transitions.insert(position, (character, next));
+                        next
+                    }
+                };
+            }
+            if folded_len > 0 {
+                nodes[node].outputs.push((needle_index, folded_len));
+            }
+        }
+
+        let mut queue = nodes[0].transitions.iter().map(|(_, next)| *next).collect::<Vec<_>>();
+        let mut queue_position = 0;
+        while let Some(&node) = queue.get(queue_position) {
+            queue_position += 1;
+            for (character, next) in nodes[node].transitions.clone() {
+                let mut fallback = nodes[node].failure;
+                let failure = loop {
+                    if let Some(failure) = nodes[fallback].transition(character) {
+                        break failure;
+                    }
+                    if fallback == 0 {
+                        break 0;
+                    }
+                    fallback = nodes[fallback].failure;
+                };
+                nodes[next].failure = failure;
+                let inherited_outputs = nodes[failure].outputs.clone();
+                nodes[next].outputs.extend(inherited_outputs);
+                queue.push(next);
+            }
+        }
+        LiteralMatcher { nodes, colorizers, case_insensitive, whole_word: self.whole_word }
+    }
+}
+
+impl From<Literals> for Rule<'_> {
+    fn from(literals: Literals) -> Self {
+        Rule::literals(literals)
+    }
+}
+
+/// Aho-Corasick automaton over the characters of the needles of some [Literals], after folding
+/// their case when matching case-insensitively.
+#[derive(Clone, Debug)]
+pub(crate) struct LiteralMatcher {
+    nodes: Vec<Node>,
+    colorizers: Vec<Colorizer>,
+    case_insensitive: bool,
+    whole_word: bool,
+}
+
+/// State of the automaton, with its transitions sorted by character, the state to fall back to
+/// when none of them applies, and the needles, by index and folded length, that end on it.
+#[derive(Clone, Debug, Default)]
+struct Node {
+    transitions: Vec<(char, usize)>,
+    failure: usize,
+    outputs: Vec<(usize, usize)>,
+}
+
+impl Node {
+    fn transition(&self, character: char) -> Option<usize> {
+        self.transitions.binary_search_by(|(other, _)| other.cmp(&character)).ok()
+            .map(|position| self.transitions[position].1)
+    }
+}
+
+impl LiteralMatcher {
+    /// Pushes the range of every match of every needle over the input along with its [Colorizer],
+    /// sorted by needle so that the needles added later take precedence.
+    pub(crate) fn find(&self, input: &str, ranges: &mut Vec<(Range<usize>, Colorizer)>) {
+        // Start offset of the original character of every folded character, and whether it's the
+        // first one folded from it, as folding can turn one character into many.
+        let mut folded_starts = Vec::new();
+        let mut matches = Vec::new();
+        let mut node = 0;
+        for (offset, original) in input.char_indices() {
+            let end = offset + original.len_utf8();
+            let mut folded = fold(original, self.case_insensitive).peekable();
+            let mut is_first = true;
+            while let Some(character) = folded.next() {
+                folded_starts.push((offset, is_first));
+                is_first = false;
+                node = self.step(node, character);
+                if folded.peek().is_some() {
+                    continue;
+                }
+                for &(needle_index, folded_len) in &self.nodes[node].outputs {
+                    let (start, starts_char) = folded_starts[folded_starts.len() - folded_len];
+                    if starts_char && (!self.whole_word || is_word_bounded(input, start, end)) {
+                        matches.push((needle_index, start..end));
+                    }
+                }
+            }
+        }
+        matches.sort_by_key(|(needle_index, _)| *needle_index);
+        ranges.extend(matches.into_iter()
+            .map(|(needle_index, range)| (range, self.colorizers[needle_index].clone())));
+    }
+
+    fn step(&self, mut node: usize, character: char) -> usize {
+        loop {
+            if let Some(next) = self.nodes[node].transition(character) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].failure;
+        }
+    }
+}
+
+/// Folds the case of a character when matching case-insensitively, returning it as is otherwise.
+fn fold(character: char, case_insensitive: bool) -> impl Iterator<Item=char> {
+    let lowercase = case_insensitive.then(|| character.to_lowercase());
+    let unchanged = (!case_insensitive).then_some(character);
+    lowercase.into_iter().flatten().chain(unchanged)
+}
+
+/// Checks that the given range of the input isn't preceded nor followed by a word character.
+fn is_word_bounded(input: &str, start: usize, end: usize) -> bool {
+    let is_word = |character: char| character.is_alphanumeric() || character == '_';
+    !input[..start].chars().next_back().is_some_and(is_word) && !input[end..].chars().next().is_some_and(is_word)
+}
@@ -0,0 +1,438 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::{Colorizer, TerminalProfile};
+use crate::ansi::apply_sgr;
+
+/// Colorizations of file names by their kind and by their name, as `ls` shows them, parsed from the
+/// `LS_COLORS` environment variable or from a `dircolors` database:
+///
+/// ```rust
+/// use string_colorization::{foreground, style, FileKind, LsColors};
+///
+/// let ls_colors: LsColors = "di=01;34:ex=01;32:*.rs=38;5;208:*README=4".parse().unwrap();
+/// assert_eq!(ls_colors.colorizer("src", FileKind::Directory), Some(&(style::Bold + foreground::Blue)));
+/// assert_eq!(ls_colors.colorizer("src/main.rs", FileKind::File), Some(&foreground::ansi256(208)));
+/// assert_eq!(ls_colors.colorizer("README", FileKind::File), Some(&style::Underline));
+/// assert_eq!(ls_colors.colorizer("build.rs", FileKind::Executable), Some(&(style::Bold + foreground::Green)));
+/// assert_eq!(ls_colors.colorizer("Cargo.toml", FileKind::File), None);
+/// ```
+///
+/// Just like `ls` does, names are only colorized by their globs when they are plain files, this
+/// is, [FileKind::File] or any kind falling back to it because it isn't colorized, see
+/// [FileKind::fallback].
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct LsColors {
+    normal: Option<Colorizer>,
+    links_as_target: bool,
+    kinds: Vec<(FileKind, Colorizer)>,
+    /// Globs along with their colorization and whether they only match names with the same case.
+    globs: Vec<(String, Colorizer, bool)>,
+}
+
+impl LsColors {
+    /// Creates an empty database, where no file name is colorized.
+    pub const fn new() -> LsColors {
+        LsColors { normal: None, links_as_target: false, kinds: Vec::new(), globs: Vec::new() }
+    }
+
+    /// Parses the `LS_COLORS` environment variable, where a missing variable results in an empty
+    /// database, this is only available through the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Result<LsColors, LsColorsError> {
+        match std::env::var_os("LS_COLORS") {
+            Some(ls_colors) => ls_colors.to_string_lossy().parse(),
+            None => Ok(LsColors::new()),
+        }
+    }
+
+    /// Parses a `dircolors` database, such as the output of `dircolors --print-database`, where
+    /// every line is a keyword followed by the SGR parameters of its colorization:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, style, FileKind, LsColors, TerminalProfile};
+    ///
+    /// let database = "\
+    ///     ## Only for terminals with colors
+    ///     TERM xterm*
+    ///     DIR 01;34 # directories
+    ///     EXEC 01;32
+    ///     .tar 01;31
+    ///     *~ 00;90
+    /// ";
+    /// let terminal = TerminalProfile::new().term(Some("xterm-256color"));
+    /// let ls_colors = LsColors::from_dircolors(database, &terminal).unwrap();
+    /// assert_eq!(ls_colors.colorizer("backup.tar", FileKind::File), Some(&(style::Bold + foreground::Red)));
+    /// assert_eq!(ls_colors.colorizer("notes~", FileKind::File), Some(&foreground::BrightBlack));
+    ///
+    /// let dumb_terminal = TerminalProfile::new().term(Some("dumb"));
+    /// assert_eq!(LsColors::from_dircolors(database, &dumb_terminal), Ok(LsColors::new()));
+    /// ```
+    ///
+    /// - Keywords are the names `dircolors` gives to every [FileKind], such as `DIR` or `EXEC`,
+    ///   extensions such as `.tar`, or globs such as `*~`, while `#` starts a comment.
+    /// - Lines of `TERM` and `COLORTERM` globs make the lines after them only apply when the `TERM`
+    ///   or `COLORTERM` of the given [TerminalProfile] match any of them, where a missing `TERM` is
+    ///   `none` and a missing `COLORTERM` is empty, just like `dircolors` does.
+    pub fn from_dircolors(database: &str, terminal: &TerminalProfile) -> Result<LsColors, LsColorsError> {
+        let term = terminal.term.as_deref().filter(|term| !term.is_empty()).unwrap_or("none");
+        let colorterm = terminal.colorterm.as_deref().unwrap_or("");
+        let mut ls_colors = LsColors::new();
+        let mut term_state = TermState::Global;
+        for line in database.lines() {
+            let line = line.trim_start();
+            let line = line.split_once(" #").or(line.split_once("\t#")).map_or(line, |(line, _)| line);
+            let mut words = line.split_whitespace();
+            let Some(keyword) = words.next().filter(|keyword| !keyword.starts_with('#')) else { continue };
+            let offset = keyword.as_ptr() as usize - database.as_ptr() as usize;
+            let value = words.next().ok_or(LsColorsError::MissingValue { offset })?;
+            let matched_term = if keyword.eq_ignore_ascii_case("TERM") {
+                Some(glob_matches(value, term, false))
+            } else if keyword.eq_ignore_ascii_case("COLORTERM") {
+                Some(glob_matches(value, colorterm, false))
+            } else {
+                None
+            };
+            match matched_term {
+                Some(true) => term_state = TermState::Matched,
+                Some(false) if term_state != TermState::Matched => term_state = TermState::Unmatched,
+                Some(false) => {}
+                None => {
+                    if term_state == TermState::Matched {
+                        term_state = TermState::Applying;
+                    }
+                    if term_state == TermState::Unmatched {
+                        continue;
+                    }
+                    if keyword.starts_with('.') {
+                        ls_colors.add(&format!("*{keyword}"), offset, value)?;
+                    } else if keyword.starts_with('*') {
+                        ls_colors.add(keyword, offset, value)?;
+                    } else if let Some((_, key)) = DIRCOLORS_KEYWORDS.iter().find(|(name, _)| name.eq_ignore_ascii_case(keyword)) {
+                        ls_colors.add(key, offset, value)?;
+                    } else if !IGNORED_KEYWORDS.iter().any(|ignored| ignored.eq_ignore_ascii_case(keyword)) {
+                        return Err(LsColorsError::UnknownKey { offset, key: keyword.to_string() });
+                    }
+                }
+            }
+        }
+        Ok(ls_colors)
+    }
+
+    /// Returns the [Colorizer] of a file name given its [FileKind], or [None] when it isn't
+    /// colorized, where only the last part of a path is matched against the globs, such as
+    /// `main.rs` for `src/main.rs`.
+    ///
+    /// The colorization of the kind is used when it has one, and if it doesn't, the one of its
+    /// [FileKind::fallback], except for [FileKind::File], which is first colorized by the last
+    /// glob matching its name, preferring the ones matching it with the same case, and last of all,
+    /// by the colorization of normal text, this is, the `no` key.
+    ///
+    /// Just like `ls` does, globs that only differ in case from another one with a different
+    /// colorization, such as `*.C` and `*.c`, only match names with the same case:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, style, FileKind, LsColors};
+    ///
+    /// let ls_colors: LsColors = "*.Rs=32:*.rs=31:*.md=4".parse().unwrap();
+    /// assert_eq!(ls_colors.colorizer("main.Rs", FileKind::File), Some(&foreground::Green));
+    /// assert_eq!(ls_colors.colorizer("main.rs", FileKind::File), Some(&foreground::Red));
+    /// assert_eq!(ls_colors.colorizer("main.RS", FileKind::File), None);
+    /// assert_eq!(ls_colors.colorizer("README.MD", FileKind::File), Some(&style::Underline));
+    /// ```
+    ///
+    /// When symbolic links are colorized as the file they point to, this is, through `ln=target`,
+    /// see [LsColors::links_as_target], the kind of that file should be given instead, as
+    /// [FileKind::Symlink] is colorized by its name just like [FileKind::File]:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, FileKind, LsColors};
+    ///
+    /// let ls_colors: LsColors = "ln=target:fi=33:*.rs=00".parse().unwrap();
+    /// assert!(ls_colors.links_as_target());
+    /// assert_eq!(ls_colors.colorizer("lib.rs", FileKind::Symlink), Some(&foreground::Yellow));
+    /// assert_eq!(ls_colors.colorizer("lib.rs", FileKind::File), Some(&foreground::Yellow));
+    /// ```
+    pub fn colorizer(&self, name: &str, kind: FileKind) -> Option<&Colorizer> {
+        let name = name.trim_end_matches('/');
+        let name = name.rsplit('/').next().unwrap_or(name);
+        let mut kind = Some(kind);
+        while let Some(current_kind) = kind {
+            if current_kind == FileKind::File {
+                let glob = [false, true].into_iter().find_map(|ignore_case| {
+                    self.globs.iter().rev()
+                        .find(|(glob, _, same_case)| !(ignore_case && *same_case) && glob_matches(glob, name, ignore_case))
+                });
+                if let Some((_, colorizer, _)) = glob.filter(|(_, colorizer, _)| !colorizer.is_plain()) {
+                    return Some(colorizer);
+                }
+            }
+            if current_kind == FileKind::Symlink && self.links_as_target {
+                kind = Some(FileKind::File);
+                continue;
+            }
+            let colorizer = self.kinds.iter().rev().find(|(kind, colorizer)| *kind == current_kind && !colorizer.is_plain());
+            if let Some((_, colorizer)) = colorizer {
+                return Some(colorizer);
+            }
+            kind = current_kind.fallback();
+        }
+        self.normal.as_ref().filter(|colorizer| !colorizer.is_plain())
+    }
+
+    /// Returns whether symbolic links are colorized as the file they point to, which `LS_COLORS`
+    /// sets through `ln=target`, see [LsColors::colorizer].
+    pub const fn links_as_target(&self) -> bool {
+        self.links_as_target
+    }
+
+    /// Adds the colorization of a key of `LS_COLORS`, being a glob when it starts with `*`, or the
+    /// two letters of a [FileKind] or of other keys of `ls` otherwise.
+    fn add(&mut self, key: &str, offset: usize, value: &str) -> Result<(), LsColorsError> {
+        if key == FileKind::Symlink.key() {
+            self.links_as_target = value == "target";
+            if self.links_as_target {
+                return Ok(());
+            }
+        }
+        let mut colorizer = Colorizer::new();
+        apply_sgr(&mut colorizer, value);
+        if key.starts_with('*') {
+            let mut same_case = false;
+            for (glob, glob_colorizer, glob_same_case) in &mut self.globs {
+                if glob != key && glob.eq_ignore_ascii_case(key) && *glob_colorizer != colorizer {
+                    *glob_same_case = true;
+                    same_case = true;
+                }
+            }
+            self.globs.push((key.to_string(), colorizer, same_case));
+        } else if key == "no" {
+            self.normal = Some(colorizer);
+        } else if let Some(kind) = FileKind::KINDS.into_iter().find(|kind| kind.key() == key) {
+            self.kinds.push((kind, colorizer));
+        } else if !IGNORED_KEYS.contains(&key) {
+            return Err(LsColorsError::UnknownKey { offset, key: key.to_string() });
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for LsColors {
+    type Err = LsColorsError;
+
+    /// Parses the value of the `LS_COLORS` environment variable, this is, a list of entries
+    /// separated by `:` such as `di=01;34:*.rs=38;5;208`, where every key is either the two
+    /// letters of a [FileKind] or a glob, and every value the SGR parameters of its colorization:
+    ///
+    /// ```rust
+    /// use string_colorization::{LsColors, LsColorsError};
+    ///
+    /// assert!("di=01;34:ln=01;36:*.tar=01;31:".parse::<LsColors>().is_ok());
+    /// assert_eq!("di=01;34:xx=1".parse::<LsColors>(), Err(LsColorsError::UnknownKey { offset: 9, key: "xx".to_string() }));
+    /// assert_eq!("di".parse::<LsColors>(), Err(LsColorsError::MissingValue { offset: 0 }));
+    /// ```
+    fn from_str(ls_colors: &str) -> Result<Self, Self::Err> {
+        let mut parsed = LsColors::new();
+        let mut offset = 0;
+        for entry in ls_colors.split(':') {
+            if !entry.is_empty() {
+                let (key, value) = entry.split_once('=').ok_or(LsColorsError::MissingValue { offset })?;
+                parsed.add(key, offset, value)?;
+            }
+            offset += entry.len() + 1;
+        }
+        Ok(parsed)
+    }
+}
+
+/// Kinds of files `ls` colorizes differently, along with the two letters of their key in
+/// `LS_COLORS`.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum FileKind {
+    /// Regular file, `fi`.
+    File,
+    /// Directory, `di`.
+    Directory,
+    /// Symbolic link, `ln`.
+    Symlink,
+    /// Symbolic link pointing to a file that doesn't exist, `or`.
+    OrphanSymlink,
+    /// File that doesn't exist but a symbolic link points to, `mi`.
+    Missing,
+    /// Named pipe, `pi`.
+    Fifo,
+    /// Socket, `so`.
+    Socket,
+    /// Door, `do`.
+    Door,
+    /// Block device, `bd`.
+    BlockDevice,
+    /// Character device, `cd`.
+    CharDevice,
+    /// Regular file with the permission to execute it, `ex`.
+    Executable,
+    /// Regular file that is set-user-ID, `su`.
+    Setuid,
+    /// Regular file that is set-group-ID, `sg`.
+    Setgid,
+    /// Regular file with capabilities, `ca`.
+    Capability,
+    /// Regular file with more than one hard link, `mh`.
+    MultiHardlink,
+    /// Directory that is sticky and writable by others, `tw`.
+    StickyOtherWritable,
+    /// Directory writable by others, `ow`.
+    OtherWritable,
+    /// Directory that is sticky, `st`.
+    Sticky,
+}
+
+impl FileKind {
+    const KINDS: [FileKind; 18] = [FileKind::File, FileKind::Directory, FileKind::Symlink,
+        FileKind::OrphanSymlink, FileKind::Missing, FileKind::Fifo, FileKind::Socket, FileKind::Door,
+        FileKind::BlockDevice, FileKind::CharDevice, FileKind::Executable, FileKind::Setuid,
+        FileKind::Setgid, FileKind::Capability, FileKind::MultiHardlink, FileKind::StickyOtherWritable,
+        FileKind::OtherWritable, FileKind::Sticky];
+
+    /// Returns the two letters of the key of this kind in `LS_COLORS`, such as `di` for
+    /// [FileKind::Directory].
+    pub const fn key(&self) -> &'static str {
+        match self {
+            FileKind::File => "fi",
+            FileKind::Directory => "di",
+            FileKind::Symlink => "ln",
+            FileKind::OrphanSymlink => "or",
+            FileKind::Missing => "mi",
+            FileKind::Fifo => "pi",
+            FileKind::Socket => "so",
+            FileKind::Door => "do",
+            FileKind::BlockDevice => "bd",
+            FileKind::CharDevice => "cd",
+            FileKind::Executable => "ex",
+            FileKind::Setuid => "su",
+            FileKind::Setgid => "sg",
+            FileKind::Capability => "ca",
+            FileKind::MultiHardlink => "mh",
+            FileKind::StickyOtherWritable => "tw",
+            FileKind::OtherWritable => "ow",
+            FileKind::Sticky => "st",
+        }
+    }
+
+    /// Returns the kind whose colorization is used when this one isn't colorized, just like `ls`
+    /// does, where special regular files fall back to [FileKind::File], special directories to
+    /// [FileKind::Directory] and orphan symbolic links to [FileKind::Symlink].
+    pub const fn fallback(&self) -> Option<FileKind> {
+        match self {
+            FileKind::Executable | FileKind::Setuid | FileKind::Setgid | FileKind::Capability
+            | FileKind::MultiHardlink => Some(FileKind::File),
+            FileKind::StickyOtherWritable | FileKind::OtherWritable | FileKind::Sticky => Some(FileKind::Directory),
+            FileKind::OrphanSymlink => Some(FileKind::Symlink),
+            _ => None,
+        }
+    }
+}
+
+/// Reason why `LS_COLORS` or a `dircolors` database couldn't be parsed, where `offset` is the
+/// position in bytes of the entry or line causing it.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum LsColorsError {
+    /// An entry or line doesn't have any value.
+    MissingValue { offset: usize },
+    /// A key or keyword isn't a kind of file nor a glob.
+    UnknownKey { offset: usize, key: String },
+}
+
+impl LsColorsError {
+    /// Position in bytes of the entry or line causing this error.
+    pub const fn offset(&self) -> usize {
+        match self {
+            LsColorsError::MissingValue { offset } | LsColorsError::UnknownKey { offset, .. } => *offset,
+        }
+    }
+}
+
+impl Display for LsColorsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LsColorsError::MissingValue { offset } => {
+                write!(f, "the entry at byte {offset} doesn't have any value")
+            }
+            LsColorsError::UnknownKey { offset, key } => {
+                write!(f, "the entry at byte {offset} has the key '{key}', which isn't a kind of file nor a glob")
+            }
+        }
+    }
+}
+
+impl core::error::Error for LsColorsError {}
+
+/// Whether the lines of a `dircolors` database apply, given the `TERM` and `COLORTERM` lines
+/// before them.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum TermState {
+    /// No `TERM` nor `COLORTERM` line was found yet.
+    Global,
+    /// The last group of `TERM` and `COLORTERM` lines matches the terminal.
+    Matched,
+    /// The lines after a matching group of `TERM` and `COLORTERM` lines are being applied.
+    Applying,
+    /// The last group of `TERM` and `COLORTERM` lines doesn't match the terminal.
+    Unmatched,
+}
+
+/// Keys of `LS_COLORS` that aren't a [FileKind] nor a glob, but `ls` still knows about.
+const IGNORED_KEYS: [&str; 5] = ["lc", "rc", "ec", "rs", "cl"];
+
+/// Keywords of a `dircolors` database that don't colorize file names.
+const IGNORED_KEYWORDS: [&str; 3] = ["COLOR", "OPTIONS", "EIGHTBIT"];
+
+/// Keywords of a `dircolors` database along with their key in `LS_COLORS`.
+const DIRCOLORS_KEYWORDS: [(&str, &str); 37] = [("NORMAL", "no"), ("NORM", "no"), ("FILE", "fi"),
+    ("RESET", "rs"), ("DIR", "di"), ("LNK", "ln"), ("LINK", "ln"), ("SYMLINK", "ln"), ("ORPHAN", "or"),
+    ("MISSING", "mi"), ("FIFO", "pi"), ("PIPE", "pi"), ("SOCK", "so"), ("BLK", "bd"), ("BLOCK", "bd"),
+    ("CHR", "cd"), ("CHAR", "cd"), ("DOOR", "do"), ("EXEC", "ex"), ("LEFT", "lc"), ("LEFTCODE", "lc"),
+    ("RIGHT", "rc"), ("RIGHTCODE", "rc"), ("END", "ec"), ("ENDCODE", "ec"), ("SUID", "su"),
+    ("SETUID", "su"), ("SGID", "sg"), ("SETGID", "sg"), ("STICKY", "st"), ("OTHER_WRITABLE", "ow"),
+    ("OWR", "ow"), ("STICKY_OTHER_WRITABLE", "tw"), ("OWT", "tw"), ("CAPABILITY", "ca"),
+    ("MULTIHARDLINK", "mh"), ("CLRTOEOL", "cl")];
+
+/// Checks whether a name matches a glob, where `*` matches any amount of characters and `?` any
+/// single character.
+fn glob_matches(glob: &str, name: &str, ignore_case: bool) -> bool {
+    let matches = |glob_char: char, name_char: char| if ignore_case {
+        glob_char.to_lowercase().eq(name_char.to_lowercase())
+    } else {
+        glob_char == name_char
+    };
+    let (mut glob_rest, mut name_rest) = (glob, name);
+    // The glob after the last star found along with the name from where it started matching.
+    let mut last_star: Option<(&str, &str)> = None;
+    while let Some(name_char) = name_rest.chars().next() {
+        let mut glob_chars = glob_rest.chars();
+        match glob_chars.next() {
+            Some('*') => {
+                last_star = Some((glob_chars.as_str(), name_rest));
+                glob_rest = glob_chars.as_str();
+            }
+            Some(glob_char) if glob_char == '?' || matches(glob_char, name_char) => {
+                glob_rest = glob_chars.as_str();
+                name_rest = &name_rest[name_char.len_utf8()..];
+            }
+            _ => match last_star {
+                Some((after_star, star_name)) => {
+                    let mut star_name_chars = star_name.chars();
+                    star_name_chars.next();
+                    last_star = Some((after_star, star_name_chars.as_str()));
+                    glob_rest = after_star;
+                    name_rest = star_name_chars.as_str();
+                }
+                None => return false,
+            },
+        }
+    }
+    glob_rest.chars().all(|glob_char| glob_char == '*')
+}
@@ -0,0 +1,210 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::{Color, Colorizer, StyledString};
+use crate::color::{COLOR_NAMES, NAMED_COLORS, STYLE_NAMES, parse_hex_color};
+
+/// Parses a text written in a tag markup into a [StyledString], where every tag colorizes the text
+/// it wraps, such as `<red>error</red>`, so colorized texts can be written without pointing to
+/// their parts by hand:
+///
+/// ```rust
+/// use string_colorization::{background, foreground, style};
+///
+/// let styled = string_colorization::parse_markup("<red>error</red>: <bold,on_blue>file</> not found").unwrap();
+/// assert_eq!(styled.text(), "error: file not found");
+/// assert_eq!(styled.spans(), [(0..5, foreground::Red), (7..11, style::Bold + background::Blue)]);
+/// ```
+///
+/// - Tags are a list of names separated by commas, which are the names of the constants of the
+///   [foreground](crate::foreground) and [style](crate::style) modules in snake case, such as
+///   `bright_red` or `bold`, the ones of the [background](crate::background) module preceded by
+///   `on_`, such as `on_blue`, or a true color such as `#ff8c0a` or `on_#ff8c0a`.
+/// - Tags are closed by `</>` or by repeating the same list of names, as in `</red>`, and they can
+///   be nested, where the colorization of the inner tags is joined over the one of the outer tags
+///   just like [Colorizer::join_with] does.
+/// - A `\` writes the character after it as is, such as `\<` for `<` or `\\` for `\`.
+///
+/// ```rust
+/// use string_colorization::{foreground, style, MarkupError};
+///
+/// let styled = string_colorization::parse_markup(r"<bold>a \<b> <green>c</green></bold>").unwrap();
+/// assert_eq!(styled.text(), "a <b> c");
+/// assert_eq!(styled.spans(), [(0..6, style::Bold), (6..7, style::Bold + foreground::Green)]);
+///
+/// assert_eq!(string_colorization::parse_markup("<red>a</green>"),
+///     Err(MarkupError::MismatchedClose { offset: 6, expected: "red".to_string(), found: "green".to_string() }));
+/// assert_eq!(string_colorization::parse_markup("<purple>a</>"),
+///     Err(MarkupError::UnknownTag { offset: 0, tag: "purple".to_string() }));
+/// ```
+pub fn parse_markup(markup: &str) -> Result<StyledString, MarkupError> {
+    let mut styled = StyledString::new();
+    let mut open_tags: Vec<(&str, usize, Colorizer)> = Vec::new();
+    let mut pending = String::new();
+    let mut offset = 0;
+    while let Some(character) = markup[offset..].chars().next() {
+        match character {
+            '\\' => {
+                let escaped = markup[offset + 1..].chars().next();
+                pending.push(escaped.unwrap_or('\\'));
+                offset += 1 + escaped.map_or(0, char::len_utf8);
+            }
+            '<' => {
+                let tag_len = markup[offset..].find('>').ok_or(MarkupError::UnterminatedTag { offset })?;
+                let tag = &markup[offset + 1..offset + tag_len];
+                let current = open_tags.last().map(|(_, _, colorizer)| colorizer.clone()).unwrap_or_default();
+                styled.push_styled(&pending, current.clone());
+                pending.clear();
+                if let Some(closed_tag) = tag.strip_prefix('/') {
+                    let (open_tag, _, _) = open_tags.pop().ok_or(MarkupError::UnexpectedClose { offset })?;
+                    let closed_tag = closed_tag.trim();
+                    if !closed_tag.is_empty() && closed_tag != open_tag.trim() {
+                        return Err(MarkupError::MismatchedClose { offset, expected: open_tag.trim().to_string(), found: closed_tag.to_string() });
+                    }
+                } else {
+                    let colorizer = parse_tag(tag).map_err(|tag| MarkupError::UnknownTag { offset, tag: tag.to_string() })?;
+                    open_tags.push((tag, offset, current.join_with(colorizer)));
+                }
+                offset += tag_len + 1;
+            }
+            character => {
+                pending.push(character);
+                offset += character.len_utf8();
+            }
+        }
+    }
+    if let Some((tag, offset, _)) = open_tags.pop() {
+        return Err(MarkupError::UnclosedTag { offset, tag: tag.trim().to_string() });
+    }
+    styled.push_str(&pending);
+    Ok(styled)
+}
+
+impl FromStr for StyledString {
+    type Err = MarkupError;
+
+    /// Parses a text written in a tag markup, see [parse_markup].
+    fn from_str(markup: &str) -> Result<Self, Self::Err> {
+        parse_markup(markup)
+    }
+}
+
+/// Reason why a text couldn't be parsed by [parse_markup], where `offset` is the position in bytes
+/// of the tag causing it.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum MarkupError {
+    /// A tag has a name that isn't a color nor a style.
+    UnknownTag { offset: usize, tag: String },
+    /// A `<` isn't followed by any `>`, use `\<` to write it as is.
+    UnterminatedTag { offset: usize },
+    /// A tag is closed when no tag is open.
+    UnexpectedClose { offset: usize },
+    /// A tag is closed with a list of names different from the one of the last open tag.
+    MismatchedClose { offset: usize, expected: String, found: String },
+    /// A tag is never closed.
+    UnclosedTag { offset: usize, tag: String },
+}
+
+impl MarkupError {
+    /// Position in bytes of the tag causing this error.
+    pub const fn offset(&self) -> usize {
+        match self {
+            MarkupError::UnknownTag { offset, .. }
+            | MarkupError::UnterminatedTag { offset }
+            | MarkupError::UnexpectedClose { offset }
+            | MarkupError::MismatchedClose { offset, .. }
+            | MarkupError::UnclosedTag { offset, .. } => *offset,
+        }
+    }
+}
+
+impl Display for MarkupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MarkupError::UnknownTag { offset, tag } => {
+                write!(f, "the tag at byte {offset} has the name '{tag}', which isn't a color nor a style")
+            }
+            MarkupError::UnterminatedTag { offset } => {
+                write!(f, "the tag at byte {offset} is never terminated by a '>'")
+            }
+            MarkupError::UnexpectedClose { offset } => {
+                write!(f, "the tag at byte {offset} closes a tag, but no tag is open")
+            }
+            MarkupError::MismatchedClose { offset, expected, found } => {
+                write!(f, "the tag at byte {offset} closes '{found}', but the last open tag is '{expected}'")
+            }
+            MarkupError::UnclosedTag { offset, tag } => {
+                write!(f, "the tag '{tag}' at byte {offset} is never closed")
+            }
+        }
+    }
+}
+
+impl core::error::Error for MarkupError {}
+
+/// Parses the list of names of a tag into a [Colorizer], or returns the first name that isn't a
+/// color nor a style.
+///
+/// This is a `const fn` so the macros of the `macros` feature check tags while compiling with the
+/// same names as [parse_markup] does.
+pub(crate) const fn parse_tag(tag: &str) -> Result<Colorizer, &str> {
+    let mut colorizer = Colorizer::new();
+    let mut rest = tag;
+    loop {
+        let mut name_len = 0;
+        while name_len < rest.len() && rest.as_bytes()[name_len] != b',' {
+            name_len += 1;
+        }
+        let (name, after_name) = rest.split_at(name_len);
+        let name = name.trim_ascii();
+        colorizer = match parse_name(colorizer, name) {
+            Some(colorizer) => colorizer,
+            None => return Err(name),
+        };
+        match after_name.split_at_checked(1) {
+            Some((_, after_comma)) => rest = after_comma,
+            None => return Ok(colorizer),
+        }
+    }
+}
+
+/// Applies a name of a tag over a [Colorizer], or returns [None] when it isn't a color nor a style.
+const fn parse_name(colorizer: Colorizer, name: &str) -> Option<Colorizer> {
+    let mut index = 0;
+    while index < STYLE_NAMES.len() {
+        if STYLE_NAMES[index].0.eq_ignore_ascii_case(name) {
+            return Some(colorizer.style(STYLE_NAMES[index].1));
+        }
+        index += 1;
+    }
+    match name.split_at_checked(3) {
+        Some((prefix, color)) if prefix.eq_ignore_ascii_case("on_") => match parse_color(color) {
+            Some(color) => Some(colorizer.background(color)),
+            None => None,
+        },
+        _ => match parse_color(name) {
+            Some(color) => Some(colorizer.foreground(color)),
+            None => None,
+        },
+    }
+}
+
+/// Parses the name of a named color or a true color written as `#rrggbb`.
+const fn parse_color(name: &str) -> Option<Color> {
+    if let [b'#', hex @ ..] = name.as_bytes() {
+        return match hex.len() {
+            6 => parse_hex_color(name.split_at(1).1),
+            _ => None,
+        };
+    }
+    let mut index = 0;
+    while index < COLOR_NAMES.len() {
+        if COLOR_NAMES[index].eq_ignore_ascii_case(name) {
+            return Some(NAMED_COLORS[index]);
+        }
+        index += 1;
+    }
+    None
+}
@@ -0,0 +1,110 @@
+use crate::{Color, Colorizer};
+
+/// Ways in which a [Colorizer] can be merged over another one, used by [Colorizer::join_using], and
+/// by [colorize_using](crate::colorize_using) and [Rule::merge](crate::Rule::merge) to tell how
+/// the rules of a call, or a single rule, colorize characters that other rules with less
+/// precedence already colorize.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug, Default)]
+pub enum MergeStrategy {
+    /// The colors of the new [Colorizer] replace the old ones when set, and their styles are
+    /// joined, this is what [Colorizer::join_with] and the `+` operator do.
+    #[default]
+    Layer,
+    /// The new [Colorizer] replaces the old one entirely, even if it doesn't set some color.
+    Replace,
+    /// The new [Colorizer] only sets the colors the old one doesn't have, so the first one wins,
+    /// while their styles are joined.
+    FillMissing,
+    /// When both [Colorizer]s set the same color and at least one of them is a true color, the
+    /// result is a true color averaging the red, green and blue values of both, while otherwise
+    /// they are layered just like [MergeStrategy::Layer] does, so they keep following the theme of
+    /// the terminal. Their styles are joined.
+    Blend,
+    /// The colors of the new [Colorizer] replace the old ones when set, while each of its styles
+    /// is toggled, such as turning [Styles::Reversed](crate::Styles::Reversed) off for an already reversed text.
+    StyleXor,
+}
+
+impl Colorizer {
+    /// Joins two [Colorizer]s according to the given [MergeStrategy], where [MergeStrategy::Layer]
+    /// is the same as [Colorizer::join_with]:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, style, MergeStrategy};
+    ///
+    /// let highlight = foreground::Red + background::Blue + style::Reversed;
+    /// let selection = foreground::Green + style::Reversed;
+    /// assert_eq!(highlight.clone().join_using(selection.clone(), MergeStrategy::Layer),
+    ///     foreground::Green + background::Blue + style::Reversed);
+    /// assert_eq!(highlight.clone().join_using(selection.clone(), MergeStrategy::Replace),
+    ///     foreground::Green + style::Reversed);
+    /// assert_eq!(highlight.clone().join_using(selection.clone(), MergeStrategy::FillMissing),
+    ///     foreground::Red + background::Blue + style::Reversed);
+    /// assert_eq!(highlight.clone().join_using(selection.clone(), MergeStrategy::StyleXor),
+    ///     foreground::Green + background::Blue);
+    /// assert_eq!(foreground::true_color(255, 0, 0).join_using(foreground::true_color(0, 0, 255), MergeStrategy::Blend),
+    ///     foreground::true_color(127, 0, 127));
+    /// assert_eq!(foreground::Red.join_using(foreground::Red, MergeStrategy::Blend), foreground::Red);
+    /// assert_eq!(foreground::Red.join_using(foreground::ansi256(208), MergeStrategy::Blend), foreground::ansi256(208));
+    /// ```
+    pub const fn join_using(mut self, new: Self, strategy: MergeStrategy) -> Self {
+        match strategy {
+            MergeStrategy::Layer => self.join_with(new),
+            MergeStrategy::Replace => new,
+            MergeStrategy::FillMissing => {
+                if self.foreground.is_none() {
+                    self.foreground = new.foreground;
+                }
+                if self.background.is_none() {
+                    self.background = new.background;
+                }
+                self.style_const = join_style_consts(self.style_const, new.style_const, false);
+                self
+            }
+            MergeStrategy::Blend => {
+                let style_const = join_style_consts(self.style_const, new.style_const, false);
+                self.foreground = blend(self.foreground, new.foreground);
+                self.background = blend(self.background, new.background);
+                self.style_const = style_const;
+                self
+            }
+            MergeStrategy::StyleXor => {
+                let style_const = join_style_consts(self.style_const, new.style_const, true);
+                self = self.join_with(Colorizer { style_const: None, ..new });
+                self.style_const = style_const;
+                self
+            }
+        }
+    }
+}
+
+/// Joins the styles of two [Colorizer]s, either adding or toggling the new ones, without giving
+/// [Styles::Clear](crate::Styles::Clear) any special meaning.
+const fn join_style_consts(old: Option<u16>, new: Option<u16>, toggle: bool) -> Option<u16> {
+    match (old, new) {
+        (Some(old), Some(new)) => {
+            let joined = if toggle { old ^ new } else { old | new };
+            if joined == 0 { None } else { Some(joined) }
+        }
+        (Some(style_const), None) | (None, Some(style_const)) => Some(style_const),
+        (None, None) => None,
+    }
+}
+
+/// Averages two colors when any of them is a true color, or returns the new one when set, and the
+/// old one otherwise.
+const fn blend(old: Option<Color>, new: Option<Color>) -> Option<Color> {
+    match (old, new) {
+        (Some(old @ Color::TrueColor { .. }), Some(new)) | (Some(old), Some(new @ Color::TrueColor { .. })) => {
+            let (old_r, old_g, old_b) = old.to_rgb();
+            let (new_r, new_g, new_b) = new.to_rgb();
+            Some(Color::TrueColor {
+                r: ((old_r as u16 + new_r as u16) / 2) as u8,
+                g: ((old_g as u16 + new_g as u16) / 2) as u8,
+                b: ((old_b as u16 + new_b as u16) / 2) as u8,
+            })
+        }
+        (_, Some(color)) | (Some(color), None) => Some(color),
+        (None, None) => None,
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{ColorSupport, TerminalProfile};
+
+/// Options deciding how colorized text is written for a single call, such as on
+/// [Colorizer::apply_with](crate::Colorizer::apply_with),
+/// [colorize_with](crate::colorize_with) or
+/// [Colorized::render_options](crate::Colorized::render_options), rather than through any global
+/// state, so that tests running in parallel or programs writing into several outputs can decide
+/// how each text gets colorized:
+///
+/// ```rust
+/// use string_colorization::{foreground, ColorSupport, RenderOptions};
+///
+/// let orange = foreground::true_color(255, 140, 10);
+/// let terminal = RenderOptions { enabled: true, depth: ColorSupport::Ansi256 };
+/// let file = RenderOptions { enabled: false, ..RenderOptions::new() };
+/// assert_eq!(orange.apply_with("Orange", terminal), "\x1b[38;5;208mOrange\x1b[0m");
+/// assert_eq!(orange.apply_with("Orange", file), "Orange");
+/// ```
+///
+/// A [ColorSupport] or a [TerminalProfile] can be given wherever these options are expected, with
+/// colors being disabled when the support is [ColorSupport::None].
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct RenderOptions {
+    /// Whether colors and styles are written at all, when false the text is written as is.
+    pub enabled: bool,
+    /// Colors the output can show, where colors beyond them are turned into the closest ones it
+    /// can show, see [Colorizer::downgrade](crate::Colorizer::downgrade).
+    pub depth: ColorSupport,
+}
+
+impl RenderOptions {
+    /// Creates the options writing every color and style as is.
+    pub const fn new() -> RenderOptions {
+        RenderOptions { enabled: true, depth: ColorSupport::TrueColor }
+    }
+
+    /// Returns the [ColorSupport] text is written with, this is, [ColorSupport::None] when these
+    /// options aren't enabled, and their depth otherwise.
+    pub const fn color_support(&self) -> ColorSupport {
+        if self.enabled { self.depth } else { ColorSupport::None }
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        RenderOptions::new()
+    }
+}
+
+impl From<ColorSupport> for RenderOptions {
+    fn from(color_support: ColorSupport) -> Self {
+        RenderOptions { enabled: color_support != ColorSupport::None, depth: color_support }
+    }
+}
+
+impl From<&TerminalProfile> for RenderOptions {
+    fn from(terminal_profile: &TerminalProfile) -> Self {
+        terminal_profile.color_support().into()
+    }
+}
+
+impl From<TerminalProfile> for RenderOptions {
+    fn from(terminal_profile: TerminalProfile) -> Self {
+        RenderOptions::from(&terminal_profile)
+    }
+}
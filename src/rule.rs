@@ -0,0 +1,341 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::ops::Range;
+
+use crate::{Colorizer, Literals, MergeStrategy};
+use crate::literal::LiteralMatcher;
+
+/// Part of a text to colorize along with the [Colorizer] to apply on it, used by
+/// [colorize](crate::colorize) and its variants.
+///
+/// The part to colorize can be given as:
+/// - A range of bytes of the input, through [Rule::bytes].
+/// - A range of characters of the input, through [Rule::chars], useful when the input isn't
+///   ASCII and counting bytes is error-prone.
+/// - A substring of the input, through [Rule::slice], or by converting a `(&str, Colorizer)`
+///   tuple, which is what [colorize](crate::colorize) has always accepted.
+/// - Every occurrence of one or many literal texts, through [Rule::literal] and [Rule::literals].
+/// - Every match of a regular expression, or every capture group of them, through `Rule::regex`
+///   and `Rule::regex_captures`, this is only available through the `regex` feature.
+///
+/// ```rust
+/// use string_colorization::{foreground, Rule};
+///
+/// let text = "¡Hola, mundo!";
+/// let colorized = string_colorization::colorize(text, None, [
+///     Rule::chars(1..5, foreground::Red),       // 'Hola', as characters
+///     Rule::bytes(8..13, foreground::Green),    // 'mundo', as bytes, as '¡' takes two of them
+/// ]);
+/// assert_eq!(colorized, "¡\x1b[31mHola\x1b[0m, \x1b[32mmundo\x1b[0m!");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Rule<'input> {
+    target: Target<'input>,
+    colorizer: Colorizer,
+    pub(crate) priority: i32,
+    pub(crate) merge_strategy: Option<MergeStrategy>,
+}
+
+/// Part of the input a [Rule] points to.
+#[derive(Clone, Debug)]
+enum Target<'input> {
+    Bytes(Range<usize>),
+    Chars(Range<usize>),
+    Slice(&'input str),
+    Literals(LiteralMatcher),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+    #[cfg(feature = "regex")]
+    RegexCaptures(regex::Regex, Vec<(String, Colorizer)>),
+}
+
+impl<'input> Rule<'input> {
+    /// Creates a [Rule] colorizing the given range of bytes of the input, the range must be within
+    /// the input and both of its ends must lie on character boundaries.
+    pub const fn bytes(range: Range<usize>, colorizer: Colorizer) -> Rule<'input> {
+        Rule { target: Target::Bytes(range), colorizer, priority: 0, merge_strategy: None }
+    }
+
+    /// Creates a [Rule] colorizing the given range of characters of the input, the range must be
+    /// within the amount of characters of the input.
+    pub const fn chars(range: Range<usize>, colorizer: Colorizer) -> Rule<'input> {
+        Rule { target: Target::Chars(range), colorizer, priority: 0, merge_strategy: None }
+    }
+
+    /// Creates a [Rule] colorizing the given substring, which must be a slice of the input itself,
+    /// rather than an equal text found elsewhere, as the position of the substring is found by
+    /// checking where it is located in memory.
+    pub const fn slice(slice: &'input str, colorizer: Colorizer) -> Rule<'input> {
+        Rule { target: Target::Slice(slice), colorizer, priority: 0, merge_strategy: None }
+    }
+
+    /// Returns the [Colorizer] applied by this [Rule].
+    pub const fn colorizer(&self) -> &Colorizer {
+        &self.colorizer
+    }
+
+    /// Creates a [Rule] colorizing every occurrence of the given text within the input:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, Rule};
+    ///
+    /// let text = "to be or not to be";
+    /// let colorized = string_colorization::colorize(text, None, [Rule::literal("be", foreground::Green)]);
+    /// assert_eq!(colorized, "to \x1b[32mbe\x1b[0m or not to \x1b[32mbe\x1b[0m");
+    /// ```
+    pub fn literal<Needle: Into<String>>(needle: Needle, colorizer: Colorizer) -> Rule<'input> {
+        Rule::literals(Literals::new().needle(needle, colorizer))
+    }
+
+    /// Creates a [Rule] colorizing every occurrence of every needle of the given [Literals], which
+    /// is the same as converting the [Literals] into a [Rule].
+    pub fn literals(literals: Literals) -> Rule<'input> {
+        Rule { target: Target::Literals(literals.compile()), colorizer: Colorizer::new(), priority: 0, merge_strategy: None }
+    }
+
+    /// Creates a [Rule] colorizing every match of the given regular expression, returning an error
+    /// if the pattern isn't valid, this is only available through the `regex` feature:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, Rule};
+    ///
+    /// let text = "Took 12ms, then 345ms";
+    /// let colorized = string_colorization::colorize(text, None, [
+    ///     Rule::regex(r"\d+ms", foreground::Yellow).unwrap(),
+    /// ]);
+    /// assert_eq!(colorized, "Took \x1b[33m12ms\x1b[0m, then \x1b[33m345ms\x1b[0m");
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str, colorizer: Colorizer) -> Result<Rule<'input>, regex::Error> {
+        Ok(Rule::from_regex(regex::Regex::new(pattern)?, colorizer))
+    }
+
+    /// Creates a [Rule] colorizing every match of an already compiled regular expression, this is
+    /// only available through the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub fn from_regex(regex: regex::Regex, colorizer: Colorizer) -> Rule<'input> {
+        Rule { target: Target::Regex(regex), colorizer, priority: 0, merge_strategy: None }
+    }
+
+    /// Creates a [Rule] colorizing the named capture groups of every match of the given regular
+    /// expression, each one with its own [Colorizer], returning an error if the pattern isn't
+    /// valid, this is only available through the `regex` feature.
+    ///
+    /// When capture groups are nested, the [Colorizer]s of the groups given later take precedence,
+    /// and naming a group the regular expression doesn't have makes the rule be rejected with
+    /// [RuleError::UnknownCaptureGroup]:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, Rule, RuleError};
+    ///
+    /// let text = "name=Jorge, lang=Rust";
+    /// let colorized = string_colorization::colorize(text, None, [
+    ///     Rule::regex_captures(r"(?P<key>\w+)=(?P<val>\w+)", [
+    ///         ("key", foreground::Blue),
+    ///         ("val", foreground::Green),
+    ///     ]).unwrap(),
+    /// ]);
+    /// assert_eq!(colorized, "\x1b[34mname\x1b[0m=\x1b[32mJorge\x1b[0m, \x1b[34mlang\x1b[0m=\x1b[32mRust\x1b[0m");
+    ///
+    /// let unknown_group = Rule::regex_captures(r"(?P<key>\w+)", [("value", foreground::Green)]);
+    /// assert_eq!(string_colorization::try_colorize(text, None, [unknown_group.unwrap()]),
+    ///     Err(RuleError::UnknownCaptureGroup { rule_index: 0, name: "value".to_string() }));
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex_captures<Name: Into<String>, Groups: IntoIterator<Item=(Name, Colorizer)>>(pattern: &str, groups: Groups) -> Result<Rule<'input>, regex::Error> {
+        Ok(Rule::from_regex_captures(regex::Regex::new(pattern)?, groups))
+    }
+
+    /// Creates a [Rule] colorizing the named capture groups of every match of an already compiled
+    /// regular expression, each one with its own [Colorizer], this is only available through the
+    /// `regex` feature.
+    #[cfg(feature = "regex")]
+    pub fn from_regex_captures<Name: Into<String>, Groups: IntoIterator<Item=(Name, Colorizer)>>(regex: regex::Regex, groups: Groups) -> Rule<'input> {
+        let groups = groups.into_iter().map(|(name, colorizer)| (name.into(), colorizer)).collect();
+        Rule { target: Target::RegexCaptures(regex, groups), colorizer: Colorizer::new(), priority: 0, merge_strategy: None }
+    }
+
+    /// Sets the priority of this [Rule], by default 0, where rules with a higher priority take
+    /// precedence over the ones with a lower one when they colorize the same characters, no matter
+    /// the order in which they are given, while rules with the same priority keep using that order,
+    /// letting the later ones take precedence.
+    ///
+    /// This allows merging rules produced independently, such as search highlights over syntax
+    /// highlighting, without carefully ordering them:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, Rule};
+    ///
+    /// let text = "let value = 10;";
+    /// let search_highlights = [Rule::literal("value", background::Yellow).priority(10)];
+    /// let syntax = [Rule::literal("let", foreground::Blue), Rule::bytes(4..9, foreground::Cyan)];
+    /// let colorized = string_colorization::colorize(text, None, search_highlights.into_iter().chain(syntax));
+    /// assert_eq!(colorized, "\x1b[34mlet\x1b[0m \x1b[36;43mvalue\x1b[0m = 10;");
+    /// ```
+    ///
+    /// The `general_colorization` of [colorize](crate::colorize) always stays below every rule,
+    /// regardless of their priorities.
+    pub fn priority(mut self, priority: i32) -> Rule<'input> {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets how this [Rule] colorizes characters already colorized by rules with less precedence,
+    /// by default, the strategy of the call colorizing the text, which is [MergeStrategy::Layer],
+    /// this is, joining them with [Colorizer::join_with], unless given to
+    /// [colorize_using](crate::colorize_using):
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, style, MergeStrategy, Rule};
+    ///
+    /// let text = "fn main() {}";
+    /// let colorized = string_colorization::colorize(text, None, [
+    ///     Rule::bytes(0..7, foreground::Blue + style::Reversed),            // Highlight
+    ///     Rule::bytes(3..9, style::Reversed).merge(MergeStrategy::StyleXor), // Selection
+    /// ]);
+    /// assert_eq!(colorized, "\x1b[7;34mfn \x1b[27mmain\x1b[0;7m()\x1b[0m {}");
+    /// ```
+    ///
+    /// To use the same strategy for every rule of a call, use
+    /// [colorize_using](crate::colorize_using) instead, where the rules setting their own strategy
+    /// through this method still override it.
+    pub fn merge(mut self, merge_strategy: MergeStrategy) -> Rule<'input> {
+        self.merge_strategy = Some(merge_strategy);
+        self
+    }
+
+    /// Turns this [Rule] into one colorizing a range of bytes when it points to a slice of the given
+    /// text, translating its offsets through the given function, so it can apply over another text
+    /// made from it.
+    pub(crate) fn translate_slice<Translate: Fn(usize) -> usize>(mut self, text: &str, translate_offset: Translate) -> Rule<'input> {
+        if let Target::Slice(slice) = self.target {
+            if let Some(start) = slice_start(text, slice) {
+                self.target = Target::Bytes(translate_offset(start)..translate_offset(start + slice.len()));
+            }
+        }
+        self
+    }
+
+    /// Finds the ranges of bytes of the input this [Rule] points to, pushing them along with the
+    /// [Colorizer] to apply on each one, or returns the reason why it can't apply to this input.
+    pub(crate) fn resolve(&self, input: &str, rule_index: usize, ranges: &mut Vec<(Range<usize>, Colorizer)>) -> Result<(), RuleError> {
+        let range = match &self.target {
+            Target::Bytes(range) => {
+                if range.start > range.end || range.end > input.len() {
+                    return Err(RuleError::InvalidRange { rule_index, range: range.clone(), len: input.len() });
+                }
+                match [range.start, range.end].into_iter().find(|offset| !input.is_char_boundary(*offset)) {
+                    Some(offset) => Err(RuleError::NotCharBoundary { rule_index, offset }),
+                    None => Ok(range.clone()),
+                }
+            }
+            Target::Chars(range) => {
+                let mut offsets = input.char_indices().map(|(offset, _)| offset).chain([input.len()]);
+                let start = offsets.nth(range.start);
+                let end = range.end.checked_sub(range.start).and_then(|distance| match distance {
+                    0 => start,
+                    distance => offsets.nth(distance - 1),
+                });
+                match (start, end) {
+                    (Some(start), Some(end)) => Ok(start..end),
+                    _ => Err(RuleError::InvalidRange { rule_index, range: range.clone(), len: input.chars().count() }),
+                }
+            }
+            Target::Slice(slice) => {
+                let start = slice_start(input, slice).ok_or(RuleError::ForeignSlice { rule_index })?;
+                Ok(start..start + slice.len())
+            }
+            Target::Literals(matcher) => {
+                matcher.find(input, ranges);
+                return Ok(());
+            }
+            #[cfg(feature = "regex")]
+            Target::Regex(regex) => {
+                ranges.extend(regex.find_iter(input).map(|found| (found.range(), self.colorizer.clone())));
+                return Ok(());
+            }
+            #[cfg(feature = "regex")]
+            Target::RegexCaptures(regex, groups) => {
+                if let Some((name, _)) = groups.iter().find(|(name, _)| !regex.capture_names().any(|group| group == Some(name.as_str()))) {
+                    return Err(RuleError::UnknownCaptureGroup { rule_index, name: name.clone() });
+                }
+                for captures in regex.captures_iter(input) {
+                    ranges.extend(groups.iter().filter_map(|(name, colorizer)|
+                        captures.name(name).map(|found| (found.range(), colorizer.clone()))));
+                }
+                return Ok(());
+            }
+        }?;
+        ranges.push((range, self.colorizer.clone()));
+        Ok(())
+    }
+}
+
+/// Returns the offset of the given slice within the text, or none if it isn't a slice of it.
+fn slice_start(text: &str, slice: &str) -> Option<usize> {
+    let text_start = text.as_ptr() as usize;
+    let slice_start = slice.as_ptr() as usize;
+    slice_start.checked_sub(text_start)
+        .filter(|start| start.checked_add(slice.len()).is_some_and(|end| end <= text.len()))
+}
+
+impl<'input> From<(&'input str, Colorizer)> for Rule<'input> {
+    fn from((slice, colorizer): (&'input str, Colorizer)) -> Self {
+        Rule::slice(slice, colorizer)
+    }
+}
+
+/// Reason why a [Rule] couldn't be applied over a text, where `rule_index` is the position of the
+/// rule within the ones given to [colorize](crate::colorize).
+///
+/// These are returned by [try_colorize](crate::try_colorize), or reported by
+/// [Colorized::rejected_rules](crate::Colorized::rejected_rules), while [colorize](crate::colorize)
+/// just ignores the rules that can't be applied.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum RuleError {
+    /// The rule targets a substring that isn't a slice of the input, such as a slice of a copy of
+    /// it.
+    ForeignSlice { rule_index: usize },
+    /// The rule targets a range that ends before it starts or that goes beyond the input, where
+    /// `len` is the amount of bytes or characters of the input, depending on the rule.
+    InvalidRange { rule_index: usize, range: Range<usize>, len: usize },
+    /// The rule targets a range of bytes where one of its ends lies in the middle of a character.
+    NotCharBoundary { rule_index: usize, offset: usize },
+    /// The rule colorizes a capture group its regular expression doesn't have, this is only
+    /// produced by `Rule::regex_captures` and `Rule::from_regex_captures`.
+    UnknownCaptureGroup { rule_index: usize, name: String },
+}
+
+impl RuleError {
+    /// Position of the rule within the ones given to [colorize](crate::colorize).
+    pub const fn rule_index(&self) -> usize {
+        match self {
+            RuleError::ForeignSlice { rule_index }
+            | RuleError::InvalidRange { rule_index, .. }
+            | RuleError::NotCharBoundary { rule_index, .. }
+            | RuleError::UnknownCaptureGroup { rule_index, .. } => *rule_index,
+        }
+    }
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RuleError::ForeignSlice { rule_index } => {
+                write!(f, "rule {rule_index} targets a substring that isn't part of the input")
+            }
+            RuleError::InvalidRange { rule_index, range, len } => {
+                write!(f, "rule {rule_index} targets the range {range:?}, which isn't valid for an input of length {len}")
+            }
+            RuleError::NotCharBoundary { rule_index, offset } => {
+                write!(f, "rule {rule_index} targets the byte {offset}, which isn't a character boundary")
+            }
+            RuleError::UnknownCaptureGroup { rule_index, name } => {
+                write!(f, "rule {rule_index} colorizes the capture group '{name}', which its regular expression doesn't have")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RuleError {}
@@ -0,0 +1,178 @@
+use core::fmt::{Display, Formatter, Write};
+
+use crate::{Color, Colorizer, STYLES, Styles};
+
+/// Escape sequence resetting every color and style, it's written after every text colorized by
+/// a [Colorizer].
+pub const RESET: &str = "\x1b[0m";
+
+/// Select Graphic Rendition (SGR) escape sequence of a [Colorizer], this is, the characters that
+/// when written before a text, make a terminal show it with the colors and styles of the
+/// [Colorizer], combined into a single sequence such as `ESC[1;31;44m`.
+///
+/// It's obtained through [Colorizer::sgr], and when the [Colorizer] doesn't set any color or style,
+/// it writes nothing at all:
+///
+/// ```rust
+/// use string_colorization::{background, foreground, style, Colorizer};
+///
+/// let colorizer = style::Bold + foreground::Red + background::Blue;
+/// assert_eq!(colorizer.sgr().to_string(), "\x1b[1;31;44m");
+/// assert_eq!(Colorizer::new().sgr().to_string(), "");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Sgr<'colorizer> {
+    pub(crate) colorizer: &'colorizer Colorizer,
+}
+
+impl Display for Sgr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut params = SgrParams { writer: f, is_first: true };
+        params.push_colorizer(self.colorizer)?;
+        params.finish()
+    }
+}
+
+/// Shortest escape sequence turning a terminal showing text with the colors and styles of one
+/// [Colorizer] into showing them with the ones of another, used by the code the macros of the
+/// `macros` feature expand to.
+#[cfg(feature = "macros")]
+#[derive(Clone, Copy, Debug)]
+pub struct Transition<'colorizer>(pub &'colorizer Colorizer, pub &'colorizer Colorizer);
+
+#[cfg(feature = "macros")]
+impl Display for Transition<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write_transition(f, self.0, self.1)
+    }
+}
+
+/// Writes the shortest escape sequence that turns a terminal showing text with the colors and
+/// styles of one [Colorizer] into showing them with the ones of another, this is, either only the
+/// parameters that change between them, or a reset followed by every parameter of the new one.
+///
+/// Nothing is written when both show text the same way.
+pub(crate) fn write_transition<Writer: Write + ?Sized>(writer: &mut Writer, from: &Colorizer, to: &Colorizer) -> core::fmt::Result {
+    if from.foreground == to.foreground && from.background == to.background
+        && STYLES.iter().all(|style| style.code().is_none() || from.has_style(style) == to.has_style(style)) {
+        return Ok(());
+    }
+    if to.is_plain() {
+        return writer.write_str(RESET);
+    }
+    let mut diff_len = LenCounter(0);
+    write_diff(&mut diff_len, from, to)?;
+    let mut reset_len = LenCounter(0);
+    write_reset(&mut reset_len, to)?;
+    if diff_len.0 <= reset_len.0 {
+        write_diff(writer, from, to)
+    } else {
+        write_reset(writer, to)
+    }
+}
+
+/// Writes a reset followed by every parameter of the given [Colorizer] in a single sequence.
+fn write_reset<Writer: Write + ?Sized>(writer: &mut Writer, to: &Colorizer) -> core::fmt::Result {
+    let mut params = SgrParams { writer, is_first: true };
+    params.push(0)?;
+    params.push_colorizer(to)?;
+    params.finish()
+}
+
+/// Writes a single sequence turning off the styles and colors only the first [Colorizer] has, and
+/// turning on the ones only the second one has.
+fn write_diff<Writer: Write + ?Sized>(writer: &mut Writer, from: &Colorizer, to: &Colorizer) -> core::fmt::Result {
+    let mut params = SgrParams { writer, is_first: true };
+    let turns_off = |style: &Styles| from.has_style(style) && !to.has_style(style);
+    // Bold and dimmed are both turned off by the same parameter, so the one that has to stay on
+    // must be turned on again.
+    let turns_off_intensity = turns_off(&Styles::Bold) || turns_off(&Styles::Dimmed);
+    let mut last_off_code = None;
+    for style in STYLES.iter().filter(|style| turns_off(style)) {
+        if let Some(off_code) = style.off_code() {
+            if last_off_code != Some(off_code) {
+                params.push(off_code)?;
+            }
+            last_off_code = Some(off_code);
+        }
+    }
+    for style in STYLES.iter().filter(|style| to.has_style(style)) {
+        let is_intensity = matches!(style, Styles::Bold | Styles::Dimmed);
+        if let Some(code) = style.code().filter(|_| !from.has_style(style) || (is_intensity && turns_off_intensity)) {
+            params.push(code)?;
+        }
+    }
+    if from.foreground != to.foreground {
+        match &to.foreground {
+            Some(foreground) => params.push_color(foreground, foreground.foreground_code())?,
+            None => params.push(39)?,
+        }
+    }
+    if from.background != to.background {
+        match &to.background {
+            Some(background) => params.push_color(background, background.background_code())?,
+            None => params.push(49)?,
+        }
+    }
+    params.finish()
+}
+
+/// Counts the bytes written into it, used to compare escape sequences without allocating them.
+struct LenCounter(usize);
+
+impl Write for LenCounter {
+    fn write_str(&mut self, string: &str) -> core::fmt::Result {
+        self.0 += string.len();
+        Ok(())
+    }
+}
+
+/// Writes the parameters of an SGR sequence separated by `;`, opening the sequence on the first
+/// parameter, so that no sequence at all is written when there are no parameters.
+struct SgrParams<'writer, Writer: Write + ?Sized> {
+    writer: &'writer mut Writer,
+    is_first: bool,
+}
+
+impl<Writer: Write + ?Sized> SgrParams<'_, Writer> {
+    fn push(&mut self, code: u8) -> core::fmt::Result {
+        if self.is_first {
+            self.is_first = false;
+            self.writer.write_str("\x1b[")?;
+        } else {
+            self.writer.write_char(';')?;
+        }
+        write!(self.writer, "{code}")
+    }
+
+    fn push_colorizer(&mut self, colorizer: &Colorizer) -> core::fmt::Result {
+        for style in colorizer.get_styles() {
+            if let Some(code) = style.code() {
+                self.push(code)?;
+            }
+        }
+        if let Some(foreground) = &colorizer.foreground {
+            self.push_color(foreground, foreground.foreground_code())?;
+        }
+        if let Some(background) = &colorizer.background {
+            self.push_color(background, background.background_code())?;
+        }
+        Ok(())
+    }
+
+    fn push_color(&mut self, color: &Color, code: u8) -> core::fmt::Result {
+        self.push(code)?;
+        match color {
+            Color::Ansi256(index) => write!(self.writer, ";5;{index}"),
+            Color::TrueColor { r, g, b } => write!(self.writer, ";2;{r};{g};{b}"),
+            _ => Ok(()),
+        }
+    }
+
+    fn finish(self) -> core::fmt::Result {
+        if !self.is_first {
+            self.writer.write_char('m')?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use alloc::string::{String, ToString};
+use core::fmt::{Display, Formatter, Write};
+use core::str::FromStr;
+
+use crate::{Color, Colorizer};
+use crate::color::{COLOR_NAMES, NAMED_COLORS, STYLE_NAMES, parse_hex_color};
+use crate::html::Hex;
+
+impl FromStr for Colorizer {
+    type Err = SpecError;
+
+    /// Parses a [Colorizer] from a spec of words separated by spaces, such as the ones written in
+    /// configuration files, where every word is either a style or a color, and colors preceded by
+    /// `on` are backgrounds:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, style, Color, Colorizer};
+    ///
+    /// let colorizer: Colorizer = "bold italic red on #1e1e2e".parse().unwrap();
+    /// assert_eq!(colorizer, style::Bold + style::Italic + foreground::Red + background::true_color(30, 30, 46));
+    /// assert_eq!("bright_blue on 208".parse(), Ok(foreground::BrightBlue + background::ansi256(208)));
+    /// assert_eq!("rgb(255, 140, 10) on #fff".parse(), Ok(foreground::true_color(255, 140, 10) + background::true_color(255, 255, 255)));
+    /// assert!("#+f+f+f".parse::<Colorizer>().is_err());
+    /// ```
+    ///
+    /// - Styles are the names of the constants of the [style](crate::style) module in snake case,
+    ///   such as `bold` or `strikethrough`.
+    /// - Colors are the names of the constants of the [foreground](crate::foreground) module in
+    ///   snake case, such as `red` or `bright_red`, an index of the 256 colors palette, such as
+    ///   `208`, or a true color written as `#rgb`, `#rrggbb` or `rgb(r, g, b)`.
+    /// - Words are matched ignoring case, and just like when building a [Colorizer], later words
+    ///   take precedence over earlier ones.
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let mut colorizer = Colorizer::new();
+        let mut words = spec_words(spec);
+        while let Some((offset, word)) = words.next() {
+            if word.eq_ignore_ascii_case("on") {
+                let (color_offset, color_word) = words.next().ok_or(SpecError::MissingColor { offset })?;
+                let color = parse_spec_color(color_word)
+                    .ok_or_else(|| SpecError::UnknownColor { offset: color_offset, word: color_word.to_string() })?;
+                colorizer = colorizer.background(color);
+            } else if let Some((_, style)) = STYLE_NAMES.iter().find(|(style_name, _)| style_name.eq_ignore_ascii_case(word)) {
+                colorizer = colorizer.style(*style);
+            } else {
+                let color = parse_spec_color(word).ok_or_else(|| SpecError::UnknownWord { offset, word: word.to_string() })?;
+                colorizer = colorizer.foreground(color);
+            }
+        }
+        Ok(colorizer)
+    }
+}
+
+impl Display for Colorizer {
+    /// Writes this [Colorizer] as a spec that parses back into it, see [Colorizer::from_str], where
+    /// styles are written first, then the foreground and last the background, and true colors are
+    /// written as `#rrggbb`:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, style, Colorizer};
+    ///
+    /// let colorizer = foreground::true_color(255, 140, 10) + style::Underline + background::BrightBlack;
+    /// assert_eq!(colorizer.to_string(), "underline #ff8c0a on bright_black");
+    /// assert_eq!(colorizer.to_string().parse(), Ok(colorizer));
+    /// assert_eq!(Colorizer::new().to_string(), "");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut separator = "";
+        for style in self.get_styles() {
+            let (style_name, _) = STYLE_NAMES.iter().find(|(_, named_style)| *named_style == style)
+                .expect("Every style has a name");
+            write!(f, "{separator}{style_name}")?;
+            separator = " ";
+        }
+        if let Some(foreground) = &self.foreground {
+            f.write_str(separator)?;
+            write_spec_color(f, foreground)?;
+            separator = " ";
+        }
+        if let Some(background) = &self.background {
+            write!(f, "{separator}on ")?;
+            write_spec_color(f, background)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reason why a spec couldn't be parsed into a [Colorizer], where `offset` is the position in bytes
+/// of the word causing it.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum SpecError {
+    /// A word isn't a color nor a style.
+    UnknownWord { offset: usize, word: String },
+    /// The word following an `on` isn't a color.
+    UnknownColor { offset: usize, word: String },
+    /// An `on` is the last word, so it isn't followed by any color.
+    MissingColor { offset: usize },
+}
+
+impl SpecError {
+    /// Position in bytes of the word causing this error.
+    pub const fn offset(&self) -> usize {
+        match self {
+            SpecError::UnknownWord { offset, .. }
+            | SpecError::UnknownColor { offset, .. }
+            | SpecError::MissingColor { offset } => *offset,
+        }
+    }
+}
+
+impl Display for SpecError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SpecError::UnknownWord { offset, word } => {
+                write!(f, "the word '{word}' at byte {offset} isn't a color nor a style")
+            }
+            SpecError::UnknownColor { offset, word } => {
+                write!(f, "the word '{word}' at byte {offset} follows an 'on', but it isn't a color")
+            }
+            SpecError::MissingColor { offset } => {
+                write!(f, "the 'on' at byte {offset} isn't followed by any color")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SpecError {}
+
+/// Splits a spec into its words along with their position in bytes, where whitespaces within
+/// parentheses don't split words, so `rgb(1, 2, 3)` is a single word.
+fn spec_words(spec: &str) -> impl Iterator<Item=(usize, &str)> {
+    let mut offset = 0;
+    core::iter::from_fn(move || {
+        let rest = &spec[offset..];
+        let start = offset + rest.len() - rest.trim_start().len();
+        let mut depth = 0_usize;
+        let len = spec[start..].find(|character: char| {
+            match character {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            depth == 0 && character.is_whitespace()
+        }).unwrap_or(spec.len() - start);
+        offset = start + len;
+        (len > 0).then(|| (start, &spec[start..offset]))
+    })
+}
+
+/// Parses a color of a spec, see [Colorizer::from_str].
+fn parse_spec_color(word: &str) -> Option<Color> {
+    if let Some(hex) = word.strip_prefix('#') {
+        return parse_hex_color(hex);
+    }
+    if let Some(channels) = word.get(..4).filter(|prefix| prefix.eq_ignore_ascii_case("rgb(")).and(word.strip_suffix(')')) {
+        let mut channels = channels[4..].split(',').map(|channel| channel.trim().parse::<u8>().ok());
+        let color = Color::TrueColor { r: channels.next()??, g: channels.next()??, b: channels.next()?? };
+        return channels.next().is_none().then_some(color);
+    }
+    if word.bytes().all(|byte| byte.is_ascii_digit()) {
+        return word.parse().ok().map(Color::Ansi256);
+    }
+    COLOR_NAMES.iter().position(|color_name| color_name.eq_ignore_ascii_case(word)).map(|index| NAMED_COLORS[index])
+}
+
+/// Writes a color of a spec, see [Colorizer::from_str].
+fn write_spec_color<Writer: Write + ?Sized>(writer: &mut Writer, color: &Color) -> core::fmt::Result {
+    match color {
+        Color::Ansi256(index) => write!(writer, "{index}"),
+        Color::TrueColor { r, g, b } => write!(writer, "{}", Hex((*r, *g, *b))),
+        named => {
+            let index = NAMED_COLORS.iter().position(|named_color| named_color == named).expect("Every other color is named");
+            writer.write_str(COLOR_NAMES[index])
+        }
+    }
+}
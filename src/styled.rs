@@ -0,0 +1,292 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Write};
+use core::ops::{Add, Bound, Range, RangeBounds};
+
+use crate::{ColorSupport, Colorized, Colorizer, RenderOptions, Rule};
+use crate::colorized::{fmt_padded, write_segments};
+
+/// Text along with the ranges of bytes of it that are colorized, and their [Colorizer], which,
+/// unlike a text with escape sequences, can still be built, sliced, split or trimmed after being
+/// colorized, and is only turned into escape sequences when rendered or displayed:
+///
+/// ```rust
+/// use string_colorization::{foreground, style, StyledString};
+///
+/// let mut status = StyledString::styled("ok", foreground::Green);
+/// status.push_str(": ");
+/// status.push_styled("3 passed", style::Bold);
+/// let status = status + ", 0 failed";
+/// assert_eq!(status.text(), "ok: 3 passed, 0 failed");
+/// assert_eq!(status.spans(), [(0..2, foreground::Green), (4..12, style::Bold)]);
+/// assert_eq!(status.render(), "\x1b[32mok\x1b[0m: \x1b[1m3 passed\x1b[0m, 0 failed");
+/// assert_eq!(status.slice(4..5).render(), "\x1b[1m3\x1b[0m");
+/// ```
+///
+/// Its spans are always sorted by their start, never overlap nor are empty, and the ones next to
+/// each other always have different [Colorizer]s, where none of them leaves the text plain.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct StyledString {
+    text: String,
+    spans: Vec<(Range<usize>, Colorizer)>,
+}
+
+impl StyledString {
+    /// Creates an empty styled string.
+    pub const fn new() -> StyledString {
+        StyledString { text: String::new(), spans: Vec::new() }
+    }
+
+    /// Creates a styled string with a text that isn't colorized.
+    pub fn plain<Text: Into<String>>(text: Text) -> StyledString {
+        StyledString { text: text.into(), spans: Vec::new() }
+    }
+
+    /// Creates a styled string with a text colorized entirely by the given [Colorizer].
+    pub fn styled<Text: Into<String>>(text: Text, colorizer: Colorizer) -> StyledString {
+        let mut styled_string = StyledString::new();
+        styled_string.push_styled(&text.into(), colorizer);
+        styled_string
+    }
+
+    /// Colorizes every substring over a string just like [colorize](crate::colorize) does, but
+    /// returns a styled string instead of a text with escape sequences:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, StyledString};
+    ///
+    /// let text = "Red, no red";
+    /// let styled = StyledString::colorize(text, None, [(&text[0..3], foreground::Red)]);
+    /// assert_eq!(styled.spans(), [(0..3, foreground::Red)]);
+    /// assert_eq!(styled.render(), string_colorization::colorize(text, None, [(&text[0..3], foreground::Red)]));
+    /// ```
+    pub fn colorize<'input, RuleT: Into<Rule<'input>>, Modifiers: IntoIterator<Item=RuleT>>(input: &'input str, general_colorization: Option<Colorizer>, input_modifiers: Modifiers) -> StyledString {
+        crate::colorize_lazy(input, general_colorization, input_modifiers).into()
+    }
+
+    /// Returns the text of this styled string, without any escape sequence.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the ranges of bytes of the text that are colorized along with their [Colorizer].
+    pub fn spans(&self) -> &[(Range<usize>, Colorizer)] {
+        &self.spans
+    }
+
+    /// Returns the length in bytes of the text, without any escape sequence.
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns whether the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Appends a text that isn't colorized.
+    pub fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// Appends a text colorized by the given [Colorizer].
+    pub fn push_styled(&mut self, text: &str, colorizer: Colorizer) {
+        let start = self.text.len();
+        self.text.push_str(text);
+        self.push_span(start..self.text.len(), colorizer);
+    }
+
+    /// Appends another styled string, keeping its colorizations.
+    pub fn append(&mut self, other: &StyledString) {
+        let offset = self.text.len();
+        self.text.push_str(&other.text);
+        for (range, colorizer) in &other.spans {
+            self.push_span(range.start + offset..range.end + offset, colorizer.clone());
+        }
+    }
+
+    /// Returns the given range of bytes of this styled string along with its colorizations, just
+    /// like slicing a [str], panicking if the range doesn't fall on characters boundaries or it
+    /// goes beyond the end of the text.
+    pub fn slice<Bytes: RangeBounds<usize>>(&self, range: Bytes) -> StyledString {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end + 1,
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => self.text.len(),
+        };
+        let mut sliced = StyledString::plain(&self.text[start..end]);
+        for (span, colorizer) in &self.spans {
+            let (span_start, span_end) = (span.start.max(start), span.end.min(end));
+            if span_start < span_end {
+                sliced.push_span(span_start - start..span_end - start, colorizer.clone());
+            }
+        }
+        sliced
+    }
+
+    /// Splits this styled string by the given separator, just like [str::split] does, keeping the
+    /// colorizations of every part:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, StyledString};
+    ///
+    /// let fields = StyledString::styled("a,b", foreground::Red) + ",c";
+    /// let fields = fields.split(",").map(|field| field.render()).collect::<Vec<_>>();
+    /// assert_eq!(fields, ["\x1b[31ma\x1b[0m", "\x1b[31mb\x1b[0m", "c"]);
+    /// ```
+    pub fn split<'styled>(&'styled self, separator: &'styled str) -> impl Iterator<Item=StyledString> + 'styled {
+        self.text.split(separator).map(|part| self.slice_of(part))
+    }
+
+    /// Splits this styled string into its lines, just like [str::lines] does, keeping the
+    /// colorizations of every line.
+    pub fn lines(&self) -> impl Iterator<Item=StyledString> + '_ {
+        self.text.lines().map(|line| self.slice_of(line))
+    }
+
+    /// Returns this styled string without leading nor trailing whitespaces, just like [str::trim]
+    /// does, keeping its colorizations:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, StyledString};
+    ///
+    /// let cell = StyledString::styled("  42  ", background::Blue);
+    /// assert_eq!(cell.trim().render(), "\x1b[44m42\x1b[0m");
+    /// ```
+    pub fn trim(&self) -> StyledString {
+        self.slice_of(self.text.trim())
+    }
+
+    /// Returns the text with its colorizations written as escape sequences, which is the same as
+    /// formatting it with `{}`.
+    pub fn render(&self) -> String {
+        self.render_with(RenderOptions::new())
+    }
+
+    /// Returns the text with its colorizations written as escape sequences following the given
+    /// [RenderOptions], or the ones of a [ColorSupport] or [TerminalProfile](crate::TerminalProfile):
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, ColorSupport, StyledString};
+    ///
+    /// let orange = StyledString::styled("Orange", foreground::true_color(255, 140, 10));
+    /// assert_eq!(orange.render_with(ColorSupport::Ansi16), "\x1b[33mOrange\x1b[0m");
+    /// assert_eq!(orange.render_with(ColorSupport::None), "Orange");
+    /// ```
+    pub fn render_with<Options: Into<RenderOptions>>(&self, render_options: Options) -> String {
+        let mut output = String::with_capacity(self.text.len());
+        write_segments(&mut output, &self.text, &self.spans, &[], render_options.into().color_support())
+            .expect("Writing into a String never fails");
+        output
+    }
+
+    /// Writes the text with its colorizations into a [core::fmt::Write], which is the same as
+    /// writing the result of [StyledString::render], but without creating any [String].
+    pub fn write_to<Writer: Write + ?Sized>(&self, writer: &mut Writer) -> core::fmt::Result {
+        write_segments(writer, &self.text, &self.spans, &[], ColorSupport::TrueColor)
+    }
+
+    /// Returns a [Colorized] over this styled string, which allows writing it in other ways, such
+    /// as HTML through [Colorized::to_html].
+    pub fn colorized(&self) -> Colorized<'_> {
+        Colorized {
+            input: Cow::Borrowed(&self.text),
+            segments: self.spans.clone(),
+            sequences: Vec::new(),
+            rejected_rules: Vec::new(),
+            render_options: RenderOptions::new(),
+        }
+    }
+
+    /// Returns the part of this styled string that the given slice of its text points to.
+    fn slice_of(&self, part: &str) -> StyledString {
+        let start = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.slice(start..start + part.len())
+    }
+
+    /// Adds a span after every other one, joining it with the last one when they are next to each
+    /// other and have the same [Colorizer], and leaving it out when it's empty or plain.
+    fn push_span(&mut self, range: Range<usize>, colorizer: Colorizer) {
+        if range.is_empty() || colorizer.is_plain() {
+            return;
+        }
+        match self.spans.last_mut() {
+            Some((last_range, last_colorizer)) if last_range.end == range.start && *last_colorizer == colorizer => {
+                last_range.end = range.end;
+            }
+            _ => self.spans.push((range, colorizer)),
+        }
+    }
+}
+
+impl Display for StyledString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt_padded(f, crate::visible_width(&self.text), |f| self.write_to(f))
+    }
+}
+
+/// Keeps the text and colorizations of a [Colorized], leaving out the escape sequences that don't
+/// colorize it, such as hyperlinks, which [colorize_ansi_lazy](crate::colorize_ansi_lazy) keeps
+/// from its input, as a [StyledString] only holds text and its colorizations:
+///
+/// ```rust
+/// use string_colorization::{foreground, Rule, StyledString};
+///
+/// let output = "\x1b]8;;https://docs.rs\x1b\\docs\x1b]8;;\x1b\\";
+/// let colorized = string_colorization::colorize_ansi_lazy(output, None, [Rule::literal("docs", foreground::Blue)]);
+/// assert_eq!(colorized.to_string(), "\x1b[34m\x1b]8;;https://docs.rs\x1b\\docs\x1b[0m\x1b]8;;\x1b\\");
+/// assert_eq!(StyledString::from(colorized).render(), "\x1b[34mdocs\x1b[0m");
+/// ```
+impl From<Colorized<'_>> for StyledString {
+    fn from(colorized: Colorized<'_>) -> Self {
+        let mut styled_string = StyledString::plain(colorized.input.into_owned());
+        for (range, colorizer) in colorized.segments {
+            styled_string.push_span(range, colorizer);
+        }
+        styled_string
+    }
+}
+
+impl From<&str> for StyledString {
+    fn from(text: &str) -> Self {
+        StyledString::plain(text)
+    }
+}
+
+impl From<String> for StyledString {
+    fn from(text: String) -> Self {
+        StyledString::plain(text)
+    }
+}
+
+impl Add<&StyledString> for StyledString {
+    type Output = StyledString;
+
+    fn add(mut self, rhs: &StyledString) -> Self::Output {
+        self.append(rhs);
+        self
+    }
+}
+
+impl Add<StyledString> for StyledString {
+    type Output = StyledString;
+
+    fn add(self, rhs: StyledString) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl Add<&str> for StyledString {
+    type Output = StyledString;
+
+    fn add(mut self, rhs: &str) -> Self::Output {
+        self.push_str(rhs);
+        self
+    }
+}
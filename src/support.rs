@@ -0,0 +1,101 @@
+use crate::{Color, Colorizer};
+use crate::color::NAMED_COLORS;
+
+/// Amount of colors a terminal, or any other output, is able to show, from none at all to true
+/// colors, so colors beyond its capabilities can be turned into the closest ones it can show
+/// through [Color::downgrade] and [Colorizer::downgrade].
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum ColorSupport {
+    /// No colors nor styles at all, such as when writing into a file.
+    None,
+    /// The 16 named colors, such as [Color::Red] or [Color::BrightBlue].
+    Ansi16,
+    /// The 256 colors palette of xterm, see [Color::Ansi256].
+    Ansi256,
+    /// Any color through its red, green and blue values, see [Color::TrueColor].
+    #[default]
+    TrueColor,
+}
+
+impl Color {
+    /// Turns this color into the closest one that can be shown with the given [ColorSupport], or
+    /// into none if it can't show colors at all.
+    ///
+    /// True colors turn into the closest color of the 6x6x6 cube or grayscale ramp of the 256
+    /// colors palette, and these turn into the closest named color, where closeness is measured
+    /// through a distance weighting red, green and blue values as human eyes perceive them:
+    ///
+    /// ```rust
+    /// use string_colorization::{Color, ColorSupport};
+    ///
+    /// let orange = Color::TrueColor { r: 255, g: 140, b: 10 };
+    /// assert_eq!(orange.downgrade(ColorSupport::TrueColor), Some(orange));
+    /// assert_eq!(orange.downgrade(ColorSupport::Ansi256), Some(Color::Ansi256(208)));
+    /// assert_eq!(orange.downgrade(ColorSupport::Ansi16), Some(Color::Yellow));
+    /// assert_eq!(orange.downgrade(ColorSupport::None), None);
+    /// assert_eq!(Color::Red.downgrade(ColorSupport::Ansi16), Some(Color::Red));
+    /// ```
+    pub fn downgrade(self, support: ColorSupport) -> Option<Color> {
+        match (self, support) {
+            (_, ColorSupport::None) => None,
+            (Color::TrueColor { .. }, ColorSupport::Ansi256) => Some(closest_ansi256(self.to_rgb())),
+            (Color::TrueColor { .. } | Color::Ansi256(_), ColorSupport::Ansi16) => Some(closest_named(self.to_rgb())),
+            _ => Some(self),
+        }
+    }
+}
+
+impl Colorizer {
+    /// Turns the colors of this [Colorizer] into the closest ones that can be shown with the given
+    /// [ColorSupport], see [Color::downgrade], or removes every color and style when it can't show
+    /// colors at all:
+    ///
+    /// ```rust
+    /// use string_colorization::{ColorSupport, Colorizer, foreground, style};
+    ///
+    /// let colorizer = style::Bold + foreground::true_color(0, 0, 255);
+    /// assert_eq!(colorizer.downgrade(ColorSupport::Ansi16), style::Bold + foreground::Blue);
+    /// assert_eq!(colorizer.downgrade(ColorSupport::None), Colorizer::new());
+    /// ```
+    pub fn downgrade(&self, support: ColorSupport) -> Colorizer {
+        if support == ColorSupport::None {
+            return Colorizer::new();
+        }
+        Colorizer {
+            foreground: self.foreground.and_then(|color| color.downgrade(support)),
+            background: self.background.and_then(|color| color.downgrade(support)),
+            style_const: self.style_const,
+        }
+    }
+}
+
+/// Finds the closest color of the 6x6x6 cube or the grayscale ramp of the 256 colors palette, the
+/// first 16 colors aren't considered as terminals often change them.
+fn closest_ansi256(rgb: (u8, u8, u8)) -> Color {
+    let closest_level = |value: u8| match value {
+        0..=47 => 0,
+        48..=114 => 1,
+        value => (value - 35) / 40,
+    };
+    let cube = Color::cube(closest_level(rgb.0), closest_level(rgb.1), closest_level(rgb.2));
+    let average = (rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3;
+    let gray = Color::grayscale((average.saturating_sub(3) / 10).min(23) as u8);
+    if distance(rgb, gray.to_rgb()) < distance(rgb, cube.to_rgb()) { gray } else { cube }
+}
+
+/// Finds the closest named color.
+fn closest_named(rgb: (u8, u8, u8)) -> Color {
+    NAMED_COLORS.into_iter()
+        .min_by_key(|color| distance(rgb, color.to_rgb()))
+        .unwrap_or(Color::White)
+}
+
+/// Squared distance between two colors weighting their red, green and blue values according to
+/// how human eyes perceive them, known as the *redmean* approximation.
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let red_mean = (r1 as u32 + r2 as u32) / 2;
+    let red = r1.abs_diff(r2) as u32;
+    let green = g1.abs_diff(g2) as u32;
+    let blue = b1.abs_diff(b2) as u32;
+    (((512 + red_mean) * red * red) >> 8) + 4 * green * green + (((767 - red_mean) * blue * blue) >> 8)
+}
@@ -0,0 +1,227 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter, Write};
+
+use crate::{Color, Colorized, Colorizer, Palette, Styles};
+use crate::ansi::text_width;
+use crate::html::{Hex, write_escaped};
+
+/// Options deciding how [Colorized::to_svg] draws a colorized text as a terminal, where every
+/// character takes as many cells of a monospace grid as columns it takes on a terminal, see
+/// [visible_width](crate::visible_width):
+///
+/// ```rust
+/// use string_colorization::{foreground, Color, Palette, SvgOptions};
+///
+/// let light_terminal = SvgOptions {
+///     foreground: Color::Black,
+///     background: Color::BrightWhite,
+///     palette: Palette::new().color(Color::Yellow, (160, 120, 0)),
+///     ..SvgOptions::new()
+/// };
+/// let text = "Warning";
+/// let svg = string_colorization::colorize_lazy(text, None, [(text, foreground::Yellow)]).to_svg(&light_terminal);
+/// assert!(svg.contains("<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>"));
+/// assert!(svg.contains("<tspan x=\"10\" fill=\"#a07800\">Warning</tspan>"));
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub struct SvgOptions {
+    /// Font family the text is drawn with, which should be a monospace one.
+    pub font_family: &'static str,
+    /// Size of the font, in pixels.
+    pub font_size: u32,
+    /// Width of every cell of the grid, in pixels.
+    pub cell_width: u32,
+    /// Height of every line, in pixels.
+    pub line_height: u32,
+    /// Space between the text and the borders of the image, in pixels.
+    pub padding: u32,
+    /// Color of the text that doesn't set any foreground color.
+    pub foreground: Color,
+    /// Color of the image behind the text that doesn't set any background color.
+    pub background: Color,
+    /// Colors shown for the 16 named colors.
+    pub palette: Palette,
+}
+
+impl SvgOptions {
+    /// Creates the options drawing a dark terminal with a 15 pixels monospace font.
+    pub const fn new() -> SvgOptions {
+        SvgOptions {
+            font_family: "monospace",
+            font_size: 15,
+            cell_width: 9,
+            line_height: 20,
+            padding: 10,
+            foreground: Color::White,
+            background: Color::Black,
+            palette: Palette::new(),
+        }
+    }
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions::new()
+    }
+}
+
+impl Colorized<'_> {
+    /// Returns the colorized text as a standalone SVG image that shows it as a terminal does, so
+    /// screenshots of a program can be made without any terminal, such as from its tests.
+    ///
+    /// Every character takes as many cells of a monospace grid as columns it takes on a terminal,
+    /// where the background color of each part is drawn as a rectangle behind it, and the text is
+    /// drawn line by line with a `<tspan>` per part, showing its foreground color as well as
+    /// whether it's bold, dimmed, italic, underlined or struck through, while reversed parts swap
+    /// their colors and hidden ones are left blank:
+    ///
+    /// ```rust
+    /// use string_colorization::{background, foreground, style, SvgOptions};
+    ///
+    /// let text = "ok: 3\nfailed: 1";
+    /// let colorized = string_colorization::colorize_lazy(text, None, [
+    ///     (&text[0..2], foreground::Green),
+    ///     (&text[6..12], style::Bold + foreground::White + background::Red),
+    /// ]);
+    /// let svg = colorized.to_svg(&SvgOptions::new());
+    /// assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"101\" height=\"60\""));
+    /// assert!(svg.contains("<rect x=\"10\" y=\"30\" width=\"54\" height=\"20\" fill=\"#cd0000\"/>"));
+    /// assert!(svg.contains("<tspan x=\"10\" fill=\"#00cd00\">ok</tspan>"));
+    /// assert!(svg.contains("<tspan x=\"10\" fill=\"#e5e5e5\" font-weight=\"bold\">failed</tspan>"));
+    /// ```
+    ///
+    /// Control characters other than tabs and line breaks are left out, as they don't take any
+    /// cell and XML doesn't allow most of them:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, SvgOptions};
+    ///
+    /// let text = "done\x07 \x1b";
+    /// let svg = string_colorization::colorize_lazy(text, None, [(&text[..5], foreground::Green)]).to_svg(&SvgOptions::new());
+    /// assert!(!svg.contains(|character: char| character.is_control() && character != '\n'));
+    /// assert!(svg.contains("<tspan x=\"10\" fill=\"#00cd00\">done</tspan>"));
+    /// ```
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let mut output = String::new();
+        self.write_svg(&mut output, options).expect("Writing into a String never fails");
+        output
+    }
+
+    /// Writes the colorized text as an SVG image into a [core::fmt::Write], which is the same as
+    /// writing the result of [Colorized::to_svg], but without creating any [String].
+    pub fn write_svg<Writer: Write + ?Sized>(&self, writer: &mut Writer, options: &SvgOptions) -> core::fmt::Result {
+        let lines = self.svg_lines();
+        let columns = lines.iter()
+            .map(|line| line.iter().map(|(text, _)| text_width(text) as u32).sum::<u32>())
+            .max().unwrap_or(0);
+        let width = columns * options.cell_width + 2 * options.padding;
+        let height = lines.len() as u32 * options.line_height + 2 * options.padding;
+        writeln!(writer, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+            viewBox=\"0 0 {width} {height}\" font-family=\"{}\" font-size=\"{}\">",
+                 SvgEscaped(options.font_family), options.font_size)?;
+        writeln!(writer, "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>", Hex(options.palette.rgb(&options.background)))?;
+        for (line_index, line) in lines.iter().enumerate() {
+            let y = options.padding + line_index as u32 * options.line_height;
+            let mut x = options.padding;
+            for (text, colorizer) in line {
+                let cells = text_width(text) as u32 * options.cell_width;
+                if let (_, Some(background)) = svg_colors(colorizer, options) {
+                    writeln!(writer, "<rect x=\"{x}\" y=\"{y}\" width=\"{cells}\" height=\"{}\" fill=\"{}\"/>",
+                             options.line_height, Hex(options.palette.rgb(&background)))?;
+                }
+                x += cells;
+            }
+            write!(writer, "<text y=\"{}\" xml:space=\"preserve\">", y + options.font_size)?;
+            let mut x = options.padding;
+            for (text, colorizer) in line {
+                let cells = text_width(text) as u32 * options.cell_width;
+                if !colorizer.has_style(&Styles::Hidden) {
+                    write_tspan(writer, text, colorizer, x, options)?;
+                }
+                x += cells;
+            }
+            writeln!(writer, "</text>")?;
+        }
+        writer.write_str("</svg>\n")
+    }
+
+    /// Splits the text into its lines, each one made of the parts whose [Colorizer] changes,
+    /// leaving out line breaks as well as the last line when it's empty.
+    fn svg_lines(&self) -> Vec<Vec<(&str, &Colorizer)>> {
+        const PLAIN: &Colorizer = &Colorizer::new();
+        let mut parts = Vec::new();
+        let mut written = 0;
+        for (range, colorizer) in &self.segments {
+            parts.push((&self.input[written..range.start], PLAIN));
+            parts.push((&self.input[range.clone()], colorizer));
+            written = range.end;
+        }
+        parts.push((&self.input[written..], PLAIN));
+
+        let mut lines = alloc::vec![Vec::new()];
+        for (text, colorizer) in parts {
+            for (index, line_part) in text.split('\n').enumerate() {
+                if index > 0 {
+                    lines.push(Vec::new());
+                }
+                let line_part = line_part.trim_end_matches('\r');
+                if !line_part.is_empty() {
+                    lines.last_mut().expect("There is always a line").push((line_part, colorizer));
+                }
+            }
+        }
+        if lines.len() > 1 && lines.last().is_some_and(Vec::is_empty) {
+            lines.pop();
+        }
+        lines
+    }
+}
+
+/// Writes a part of a line as a `<tspan>` placed on its cell, with the attributes showing its
+/// foreground color and styles.
+fn write_tspan<Writer: Write + ?Sized>(writer: &mut Writer, text: &str, colorizer: &Colorizer, x: u32, options: &SvgOptions) -> core::fmt::Result {
+    let (foreground, _) = svg_colors(colorizer, options);
+    write!(writer, "<tspan x=\"{x}\" fill=\"{}\"", Hex(options.palette.rgb(&foreground)))?;
+    if colorizer.has_style(&Styles::Bold) {
+        writer.write_str(" font-weight=\"bold\"")?;
+    }
+    if colorizer.has_style(&Styles::Dimmed) {
+        writer.write_str(" fill-opacity=\"0.5\"")?;
+    }
+    if colorizer.has_style(&Styles::Italic) {
+        writer.write_str(" font-style=\"italic\"")?;
+    }
+    match (colorizer.has_style(&Styles::Underline), colorizer.has_style(&Styles::Strikethrough)) {
+        (true, true) => writer.write_str(" text-decoration=\"underline line-through\"")?,
+        (true, false) => writer.write_str(" text-decoration=\"underline\"")?,
+        (false, true) => writer.write_str(" text-decoration=\"line-through\"")?,
+        (false, false) => {}
+    }
+    writer.write_char('>')?;
+    // Control characters don't take any cell and most of them aren't allowed in XML, so they are
+    // left out, except for tabs, which take a single one.
+    text.split(|character: char| character.is_control() && character != '\t')
+        .try_for_each(|part| write_escaped(writer, part))?;
+    writer.write_str("</tspan>")
+}
+
+/// Returns the color a part of the text is drawn with, and the one of the rectangle behind it, if
+/// any, swapping them when reversed and taking the ones of the options in place of the ones the
+/// [Colorizer] doesn't set.
+fn svg_colors(colorizer: &Colorizer, options: &SvgOptions) -> (Color, Option<Color>) {
+    if colorizer.has_style(&Styles::Reversed) {
+        (colorizer.background.unwrap_or(options.background), Some(colorizer.foreground.unwrap_or(options.foreground)))
+    } else {
+        (colorizer.foreground.unwrap_or(options.foreground), colorizer.background)
+    }
+}
+
+/// Writes a text escaping the characters that have a special meaning in XML.
+struct SvgEscaped<'text>(&'text str);
+
+impl Display for SvgEscaped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write_escaped(f, self.0)
+    }
+}
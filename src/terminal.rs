@@ -0,0 +1,173 @@
+use alloc::string::{String, ToString};
+
+use crate::ColorSupport;
+
+/// Environment in which some output will be shown, used to find out its [ColorSupport] without
+/// relying on any global state, so a program writing both into a file and a terminal can colorize
+/// each of them differently.
+///
+/// With the `std` feature, it can be read from the environment variables and from whether a stream
+/// is a terminal through `TerminalProfile::for_stream`, `TerminalProfile::stdout` or
+/// `TerminalProfile::stderr`, while without it, it can be built manually:
+///
+/// ```rust
+/// use string_colorization::{ColorSupport, TerminalProfile};
+///
+/// let terminal = TerminalProfile::new().is_tty(true).term(Some("xterm-256color"));
+/// assert_eq!(terminal.color_support(), ColorSupport::Ansi256);
+/// let file = TerminalProfile::new().is_tty(false).term(Some("xterm-256color"));
+/// assert_eq!(file.color_support(), ColorSupport::None);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct TerminalProfile {
+    no_color: Option<String>,
+    clicolor: Option<String>,
+    clicolor_force: Option<String>,
+    pub(crate) colorterm: Option<String>,
+    pub(crate) term: Option<String>,
+    is_tty: bool,
+}
+
+impl TerminalProfile {
+    /// Creates a profile where no environment variable is set and the output isn't a terminal.
+    pub const fn new() -> TerminalProfile {
+        TerminalProfile { no_color: None, clicolor: None, clicolor_force: None, colorterm: None, term: None, is_tty: false }
+    }
+
+    /// Creates a profile reading the `NO_COLOR`, `CLICOLOR`, `CLICOLOR_FORCE`, `COLORTERM` and
+    /// `TERM` environment variables, where the output isn't considered a terminal, this is only
+    /// available through the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn from_env() -> TerminalProfile {
+        let var = |name: &str| std::env::var_os(name).map(|value| value.to_string_lossy().into_owned());
+        TerminalProfile {
+            no_color: var("NO_COLOR"),
+            clicolor: var("CLICOLOR"),
+            clicolor_force: var("CLICOLOR_FORCE"),
+            colorterm: var("COLORTERM"),
+            term: var("TERM"),
+            is_tty: false,
+        }
+    }
+
+    /// Creates a profile reading the environment variables, see [TerminalProfile::from_env], for
+    /// the given stream, which is considered a terminal if it is one, this is only available
+    /// through the `std` feature:
+    ///
+    /// ```rust
+    /// use string_colorization::{foreground, ColorSupport, TerminalProfile};
+    ///
+    /// let file = std::fs::File::open("Cargo.toml").unwrap();
+    /// let file_profile = TerminalProfile::for_stream(&file).clicolor_force(None);
+    /// assert_eq!(file_profile, TerminalProfile::from_env().is_tty(false).clicolor_force(None));
+    /// assert_eq!(file_profile.color_support(), ColorSupport::None);
+    ///
+    /// let message = "Saved";
+    /// let mut log = Vec::new();
+    /// string_colorization::colorize_lazy(message, None, [(message, foreground::Green)])
+    ///     .color_support(file_profile.color_support())
+    ///     .write_to_io(&mut log)
+    ///     .unwrap();
+    /// assert_eq!(log, b"Saved");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn for_stream<Stream: std::io::IsTerminal>(stream: &Stream) -> TerminalProfile {
+        TerminalProfile::from_env().is_tty(stream.is_terminal())
+    }
+
+    /// Creates a profile for the standard output, see [TerminalProfile::for_stream], this is only
+    /// available through the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn stdout() -> TerminalProfile {
+        TerminalProfile::for_stream(&std::io::stdout())
+    }
+
+    /// Creates a profile for the standard error, see [TerminalProfile::for_stream], this is only
+    /// available through the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn stderr() -> TerminalProfile {
+        TerminalProfile::for_stream(&std::io::stderr())
+    }
+
+    /// Sets the value of `NO_COLOR`, which disables colors when set to anything but an empty text.
+    pub fn no_color(mut self, value: Option<&str>) -> TerminalProfile {
+        self.no_color = value.map(ToString::to_string);
+        self
+    }
+
+    /// Sets the value of `CLICOLOR`, which disables colors when set to `0`.
+    pub fn clicolor(mut self, value: Option<&str>) -> TerminalProfile {
+        self.clicolor = value.map(ToString::to_string);
+        self
+    }
+
+    /// Sets the value of `CLICOLOR_FORCE`, which enables colors even if the output isn't a terminal
+    /// when set to anything but `0` or an empty text, unless `NO_COLOR` disables them.
+    pub fn clicolor_force(mut self, value: Option<&str>) -> TerminalProfile {
+        self.clicolor_force = value.map(ToString::to_string);
+        self
+    }
+
+    /// Sets the value of `COLORTERM`, which enables true colors when set to `truecolor` or `24bit`.
+    pub fn colorterm(mut self, value: Option<&str>) -> TerminalProfile {
+        self.colorterm = value.map(ToString::to_string);
+        self
+    }
+
+    /// Sets the value of `TERM`, which disables colors when set to `dumb`, and enables the 256 colors
+    /// palette or true colors when naming a terminal supporting them, such as `xterm-256color` or
+    /// `xterm-direct`.
+    pub fn term(mut self, value: Option<&str>) -> TerminalProfile {
+        self.term = value.map(ToString::to_string);
+        self
+    }
+
+    /// Sets whether the output is a terminal, as colors are only enabled for terminals unless
+    /// `CLICOLOR_FORCE` says otherwise.
+    pub fn is_tty(mut self, is_tty: bool) -> TerminalProfile {
+        self.is_tty = is_tty;
+        self
+    }
+
+    /// Finds out the [ColorSupport] of the output, where:
+    /// - `NO_COLOR` set to anything but an empty text disables colors.
+    /// - Otherwise, `CLICOLOR_FORCE` set to anything but `0` or an empty text enables them.
+    /// - Otherwise, colors are disabled when `CLICOLOR` is `0`, when the output isn't a terminal or
+    ///   when `TERM` is `dumb`.
+    /// - When enabled, `COLORTERM` and `TERM` tell whether true colors or the 256 colors palette
+    ///   are supported, using the 16 named colors otherwise.
+    ///
+    /// ```rust
+    /// use string_colorization::{ColorSupport, TerminalProfile};
+    ///
+    /// let terminal = TerminalProfile::new().is_tty(true);
+    /// assert_eq!(terminal.clone().colorterm(Some("truecolor")).color_support(), ColorSupport::TrueColor);
+    /// assert_eq!(terminal.clone().term(Some("dumb")).color_support(), ColorSupport::None);
+    /// assert_eq!(terminal.clone().no_color(Some("1")).color_support(), ColorSupport::None);
+    /// assert_eq!(terminal.clone().clicolor(Some("0")).color_support(), ColorSupport::None);
+    /// assert_eq!(terminal.color_support(), ColorSupport::Ansi16);
+    ///
+    /// let piped = TerminalProfile::new().term(Some("xterm-256color"));
+    /// assert_eq!(piped.clone().color_support(), ColorSupport::None);
+    /// assert_eq!(piped.clicolor_force(Some("1")).color_support(), ColorSupport::Ansi256);
+    /// ```
+    pub fn color_support(&self) -> ColorSupport {
+        let is_set = |value: &Option<String>| value.as_deref().is_some_and(|value| !value.is_empty());
+        let term = self.term.as_deref().unwrap_or_default();
+        if is_set(&self.no_color) {
+            return ColorSupport::None;
+        }
+        let is_forced = is_set(&self.clicolor_force) && self.clicolor_force.as_deref() != Some("0");
+        if !is_forced && (self.clicolor.as_deref() == Some("0") || !self.is_tty || term == "dumb") {
+            return ColorSupport::None;
+        }
+        let colorterm = self.colorterm.as_deref().unwrap_or_default();
+        if matches!(colorterm, "truecolor" | "24bit") || ["truecolor", "24bit", "direct"].iter().any(|name| term.contains(name)) {
+            ColorSupport::TrueColor
+        } else if term.contains("256color") {
+            ColorSupport::Ansi256
+        } else {
+            ColorSupport::Ansi16
+        }
+    }
+}